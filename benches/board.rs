@@ -0,0 +1,64 @@
+use chrono::{
+    Duration,
+    TimeZone,
+    Utc,
+};
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use rusty_rails::departure::Departure;
+use rusty_rails::departure_board::DepartureBoard;
+
+const ROWS: usize = 150;
+
+fn departures() -> Vec<Departure> {
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 6, 0, 0).unwrap();
+    (0..ROWS)
+        .map(|index| {
+            let scheduled_time = start + Duration::minutes(index as i64 * 4);
+            let expected_time = (index % 3 == 0).then(|| scheduled_time + Duration::minutes(index as i64 % 7));
+            Departure::new(
+                format!("Destination {}", index % 12),
+                scheduled_time,
+                expected_time,
+                vec!["London Bridge".to_string(), "East Croydon".to_string(), "Gatwick Airport".to_string()],
+                Some((index % 16) as u8 + 1),
+                "On time".to_string(),
+                None,
+                "Southern".to_string(),
+            )
+        })
+        .collect()
+}
+
+fn board() -> DepartureBoard {
+    let mut board = DepartureBoard::new();
+    for departure in departures() {
+        board.add_departure(departure);
+    }
+    board
+}
+
+fn render(c: &mut Criterion) {
+    let board = board();
+    let mut buffer = String::new();
+    c.bench_function("render 150 departures", |b| {
+        b.iter(|| {
+            buffer.clear();
+            board.render_departures(black_box(&mut buffer)).unwrap();
+        })
+    });
+}
+
+fn refresh(c: &mut Criterion) {
+    let now = Utc.with_ymd_and_hms(2024, 3, 1, 6, 0, 0).unwrap();
+    let mut board = board();
+    let departures = departures();
+    c.bench_function("refresh 150 departures", |b| b.iter(|| board.refresh(black_box(departures.clone()), now)));
+}
+
+criterion_group!(benches, render, refresh);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,106 @@
+use std::fmt::{
+    self,
+    Display,
+    Write,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use chrono_tz::Tz;
+
+use crate::departure::Departure;
+use crate::times::BOARD_TIMEZONE;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldChange {
+    ExpectedTime { old: Option<DateTime<Utc>>, new: Option<DateTime<Utc>> },
+    Platform { old: Option<u8>, new: Option<u8> },
+    Status { old: String, new: String },
+    DelayReason { old: Option<String>, new: Option<String> },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BoardEvent {
+    ServiceAdded(Departure),
+    ServiceUpdated { departure: Departure, changes: Vec<FieldChange> },
+    ServiceDeparted(Departure),
+    ServiceCancelled(Departure),
+    ServiceRemoved(Departure),
+    BoardRefreshed { services: usize },
+    FetchFailed { reason: String },
+}
+
+impl BoardEvent {
+    pub fn departure(&self) -> Option<&Departure> {
+        match self {
+            BoardEvent::ServiceAdded(departure)
+            | BoardEvent::ServiceUpdated { departure, .. }
+            | BoardEvent::ServiceDeparted(departure)
+            | BoardEvent::ServiceCancelled(departure)
+            | BoardEvent::ServiceRemoved(departure) => Some(departure),
+            BoardEvent::BoardRefreshed { .. } | BoardEvent::FetchFailed { .. } => None,
+        }
+    }
+
+    pub fn describe_in(&self, timezone: &Tz) -> String {
+        let mut description = String::new();
+        self.write_description_in(&mut description, timezone).expect("writing to a String cannot fail");
+        description
+    }
+
+    pub fn write_description_in<W: Write>(&self, out: &mut W, timezone: &Tz) -> fmt::Result {
+        match self {
+            BoardEvent::ServiceAdded(departure) => {
+                write_service(out, departure, timezone)?;
+                out.write_str(" added")
+            }
+            BoardEvent::ServiceUpdated { departure, changes } => {
+                write_service(out, departure, timezone)?;
+                write!(out, " updated ({} changes)", changes.len())
+            }
+            BoardEvent::ServiceDeparted(departure) => {
+                write_service(out, departure, timezone)?;
+                out.write_str(" departed")
+            }
+            BoardEvent::ServiceCancelled(departure) => {
+                write_service(out, departure, timezone)?;
+                out.write_str(" cancelled")
+            }
+            BoardEvent::ServiceRemoved(departure) => {
+                write_service(out, departure, timezone)?;
+                out.write_str(" left the board")
+            }
+            BoardEvent::BoardRefreshed { services } => write!(out, "Board refreshed with {} services", services),
+            BoardEvent::FetchFailed { reason } => write!(out, "Fetch failed: {}", reason),
+        }
+    }
+}
+
+impl Display for BoardEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_description_in(f, &BOARD_TIMEZONE)
+    }
+}
+
+fn write_service<W: Write>(out: &mut W, departure: &Departure, timezone: &Tz) -> fmt::Result {
+    write!(out, "{} {} service", departure.scheduled_time().with_timezone(timezone).format("%H:%M"), departure.destination())
+}
+
+pub fn field_changes(previous: &Departure, current: &Departure) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    if previous.expected_time() != current.expected_time() {
+        changes.push(FieldChange::ExpectedTime { old: *previous.expected_time(), new: *current.expected_time() });
+    }
+    if previous.platform() != current.platform() {
+        changes.push(FieldChange::Platform { old: *previous.platform(), new: *current.platform() });
+    }
+    if previous.status() != current.status() {
+        changes.push(FieldChange::Status { old: previous.status().to_string(), new: current.status().to_string() });
+    }
+    if previous.delay_reason() != current.delay_reason() {
+        changes.push(FieldChange::DelayReason { old: previous.delay_reason().clone(), new: current.delay_reason().clone() });
+    }
+    changes
+}
\ No newline at end of file
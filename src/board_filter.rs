@@ -0,0 +1,48 @@
+use crate::station_code::StationCode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterType {
+    To,
+    From,
+}
+
+impl FilterType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterType::To => "to",
+            FilterType::From => "from",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardFilter {
+    crs: StationCode,
+    filter_type: FilterType,
+}
+
+impl BoardFilter {
+    pub fn new(crs: StationCode, filter_type: FilterType) -> Self {
+        Self { crs, filter_type }
+    }
+
+    pub fn departing_to(crs: StationCode) -> Self {
+        Self::new(crs, FilterType::To)
+    }
+
+    pub fn arriving_from(crs: StationCode) -> Self {
+        Self::new(crs, FilterType::From)
+    }
+
+    pub fn crs(&self) -> &StationCode {
+        &self.crs
+    }
+
+    pub fn filter_type(&self) -> &FilterType {
+        &self.filter_type
+    }
+
+    pub fn query_parameters(&self) -> [(&'static str, &str); 2] {
+        [("filterCrs", self.crs.as_str()), ("filterType", self.filter_type.as_str())]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+};
+
+use crate::station_code::StationCode;
+use crate::times::{
+    resolve_time,
+    BOARD_TIMEZONE,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallingPoint {
+    location_name: String,
+    #[serde(default, deserialize_with = "deserialize_optional_crs")]
+    crs: Option<StationCode>,
+    #[serde(default, rename = "st")]
+    scheduled: Option<String>,
+    #[serde(default, rename = "et")]
+    expected: Option<String>,
+    #[serde(default, rename = "at")]
+    actual: Option<String>,
+}
+
+impl CallingPoint {
+    pub fn new(location_name: String, crs: Option<StationCode>, scheduled: Option<String>, expected: Option<String>, actual: Option<String>) -> Self {
+        Self { location_name, crs, scheduled, expected, actual }
+    }
+
+    pub fn location_name(&self) -> &str {
+        &self.location_name
+    }
+
+    pub fn crs(&self) -> &Option<StationCode> {
+        &self.crs
+    }
+
+    pub fn display_name(&self) -> String {
+        match &self.crs {
+            Some(crs) => format!("{} ({})", self.location_name, crs),
+            None => self.location_name.clone(),
+        }
+    }
+
+    pub fn scheduled(&self) -> &Option<String> {
+        &self.scheduled
+    }
+
+    pub fn expected(&self) -> &Option<String> {
+        &self.expected
+    }
+
+    pub fn actual(&self) -> &Option<String> {
+        &self.actual
+    }
+
+    pub fn scheduled_time(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        resolve_time(self.scheduled.as_deref()?, &now.with_timezone(&BOARD_TIMEZONE))
+    }
+
+    pub fn expected_time(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.expected.as_deref()?.trim() {
+            expected if expected.eq_ignore_ascii_case("on time") => self.scheduled_time(now),
+            expected => resolve_time(expected, &now.with_timezone(&BOARD_TIMEZONE)),
+        }
+    }
+
+    pub fn actual_time(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        resolve_time(self.actual.as_deref()?, &now.with_timezone(&BOARD_TIMEZONE))
+    }
+}
+
+fn deserialize_optional_crs<'de, D>(deserializer: D) -> Result<Option<StationCode>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let crs: Option<String> = Option::deserialize(deserializer)?;
+    Ok(crs.and_then(|crs| StationCode::new(&crs).ok()))
+}
\ No newline at end of file
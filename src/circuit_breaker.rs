@@ -0,0 +1,68 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    consecutive_failures: u32,
+    opened_at: Option<DateTime<Utc>>,
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self { failure_threshold, cooldown, consecutive_failures: 0, opened_at: None, probing: false }
+    }
+
+    pub fn state(&self, now: DateTime<Utc>) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(_) if self.probing => CircuitState::HalfOpen,
+            Some(opened_at) if now >= opened_at + self.cooldown => CircuitState::HalfOpen,
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    pub fn allows_request(&mut self, now: DateTime<Utc>) -> bool {
+        match self.state(now) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                if self.probing {
+                    false
+                } else {
+                    self.probing = true;
+                    true
+                }
+            }
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.probing = false;
+    }
+
+    pub fn record_failure(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures += 1;
+        if self.probing || self.consecutive_failures >= self.failure_threshold {
+            self.opened_at = Some(now);
+        }
+        self.probing = false;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt::{
+    self,
+    Display,
+};
+use std::fs;
+use std::io;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::station_code::{
+    StationCode,
+    StationCodeError,
+};
+use crate::stations::{
+    self,
+    ResolveError,
+};
+
+const STATION_VARIABLE: &str = "RUSTY_RAILS_STATION";
+const REFRESH_INTERVAL_VARIABLE: &str = "RUSTY_RAILS_REFRESH_INTERVAL";
+const ROWS_VARIABLE: &str = "RUSTY_RAILS_ROWS";
+
+pub const CONFIG_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    version: Option<u32>,
+    default_station: Option<String>,
+    refresh_interval: Option<u64>,
+    rows: Option<usize>,
+    aliases: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, source: toml::de::Error },
+    InvalidVariable { variable: &'static str, value: String },
+    InvalidAlias { alias: String, source: StationCodeError },
+    UnsupportedVersion { path: PathBuf, version: u32 },
+    DuplicateAlias { path: PathBuf, alias: String, other: String },
+    InvalidRefreshInterval { origin: String },
+    UnknownStation(String),
+    AmbiguousStation(ResolveError),
+}
+
+impl Config {
+    pub fn new(default_station: Option<String>, refresh_interval: Option<u64>, rows: Option<usize>) -> Self {
+        Self { version: None, default_station, refresh_interval, rows, aliases: HashMap::new() }
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        let config_home = match env::var_os("XDG_CONFIG_HOME") {
+            Some(config_home) if !config_home.is_empty() => PathBuf::from(config_home),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+        Some(config_home.join("rusty_rails").join("config.toml"))
+    }
+
+    pub fn load() -> Result<Self, ConfigError> {
+        match Self::path() {
+            Some(path) if path.exists() => Self::load_from(path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: PathBuf) -> Result<Self, ConfigError> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(source) => return Err(ConfigError::Io { path, source }),
+        };
+        let config: Self = match toml::from_str(&contents) {
+            Ok(config) => config,
+            Err(source) => return Err(ConfigError::Parse { path, source }),
+        };
+        config.check_version(&path)?.check_aliases(&path)?.check_refresh_interval(|| path.display().to_string())
+    }
+
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let config = Self {
+            version: None,
+            default_station: env::var(STATION_VARIABLE).ok(),
+            refresh_interval: parse_variable(REFRESH_INTERVAL_VARIABLE)?,
+            rows: parse_variable(ROWS_VARIABLE)?,
+            aliases: HashMap::new(),
+        };
+        config.check_refresh_interval(|| REFRESH_INTERVAL_VARIABLE.to_string())
+    }
+
+    pub fn resolve(cli: Config) -> Result<Self, ConfigError> {
+        let cli = cli.check_refresh_interval(|| "the command line".to_string())?;
+        Ok(Self::load()?.merge(Self::from_env()?).merge(cli))
+    }
+
+    pub fn merge(mut self, overrides: Config) -> Self {
+        self.default_station = overrides.default_station.or(self.default_station);
+        self.refresh_interval = overrides.refresh_interval.or(self.refresh_interval);
+        self.rows = overrides.rows.or(self.rows);
+        self.aliases.extend(overrides.aliases);
+        self
+    }
+
+    pub fn version(&self) -> &Option<u32> {
+        &self.version
+    }
+
+    pub fn default_station(&self) -> &Option<String> {
+        &self.default_station
+    }
+
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval.map(Duration::from_secs)
+    }
+
+    pub fn rows(&self) -> &Option<usize> {
+        &self.rows
+    }
+
+    pub fn aliases(&self) -> &HashMap<String, String> {
+        &self.aliases
+    }
+
+    pub fn resolve_station(&self, name: &str) -> Result<StationCode, ConfigError> {
+        let alias = self.aliases.iter().find(|(alias, _)| alias.eq_ignore_ascii_case(name.trim()));
+        match alias {
+            Some((alias, code)) => StationCode::new(code).map_err(|source| ConfigError::InvalidAlias { alias: alias.clone(), source }),
+            None => stations::resolve(name).map_err(|error| match error {
+                ResolveError::Unknown(name) => ConfigError::UnknownStation(name),
+                ambiguous => ConfigError::AmbiguousStation(ambiguous),
+            }),
+        }
+    }
+
+    fn check_version(self, path: &Path) -> Result<Self, ConfigError> {
+        match self.version {
+            Some(version) if version != CONFIG_VERSION => Err(ConfigError::UnsupportedVersion { path: path.to_path_buf(), version }),
+            _ => Ok(self),
+        }
+    }
+
+    fn check_aliases(self, path: &Path) -> Result<Self, ConfigError> {
+        let mut aliases: Vec<&String> = self.aliases.keys().collect();
+        aliases.sort_by_key(|alias| (alias.to_ascii_lowercase(), alias.to_string()));
+        match aliases.windows(2).find(|pair| pair[0].eq_ignore_ascii_case(pair[1])) {
+            Some(pair) => Err(ConfigError::DuplicateAlias { path: path.to_path_buf(), alias: pair[0].clone(), other: pair[1].clone() }),
+            None => Ok(self),
+        }
+    }
+
+    fn check_refresh_interval<F: FnOnce() -> String>(self, origin: F) -> Result<Self, ConfigError> {
+        match self.refresh_interval {
+            Some(0) => Err(ConfigError::InvalidRefreshInterval { origin: origin() }),
+            _ => Ok(self),
+        }
+    }
+}
+
+fn parse_variable<T: std::str::FromStr>(variable: &'static str) -> Result<Option<T>, ConfigError> {
+    match env::var(variable) {
+        Ok(value) => value.trim().parse().map(Some).map_err(|_| ConfigError::InvalidVariable { variable, value }),
+        Err(_) => Ok(None),
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => write!(f, "could not read config file {}: {}", path.display(), source),
+            ConfigError::Parse { path, source } => write!(f, "could not parse config file {}: {}", path.display(), source),
+            ConfigError::InvalidVariable { variable, value } => write!(f, "{} is set to '{}', which is not a whole number", variable, value),
+            ConfigError::InvalidAlias { alias, source } => write!(f, "alias '{}' is invalid: {}", alias, source),
+            ConfigError::UnsupportedVersion { path, version } => {
+                write!(f, "config file {} has version {}, but only version {} is supported; the file was left unchanged", path.display(), version, CONFIG_VERSION)
+            }
+            ConfigError::DuplicateAlias { path, alias, other } => {
+                write!(f, "config file {} defines aliases '{}' and '{}', which differ only by case", path.display(), alias, other)
+            }
+            ConfigError::InvalidRefreshInterval { origin } => write!(f, "refresh_interval from {} must be at least 1 second", origin),
+            ConfigError::UnknownStation(name) => write!(f, "'{}' is not a station code, alias or known station name", name),
+            ConfigError::AmbiguousStation(source) => write!(f, "{}; use a station code or a longer name", source),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Parse { source, .. } => Some(source),
+            ConfigError::InvalidAlias { source, .. } => Some(source),
+            ConfigError::AmbiguousStation(source) => Some(source),
+            ConfigError::InvalidVariable { .. }
+            | ConfigError::UnsupportedVersion { .. }
+            | ConfigError::DuplicateAlias { .. }
+            | ConfigError::InvalidRefreshInterval { .. }
+            | ConfigError::UnknownStation(_) => None,
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,22 @@
+use std::fmt::{
+    self,
+    Display,
+    Write,
+};
+
 use chrono::{
     DateTime,
+    Duration,
+    TimeZone,
     Utc,
 };
 
+use crate::operator::{
+    self,
+    OperatorInfo,
+};
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Departure {
     destination: String,
     scheduled_time: DateTime<Utc>,
@@ -12,22 +26,87 @@ pub struct Departure {
     status: String,
     delay_reason: Option<String>,
     operator: String,
+    service_id: Option<String>,
 }
 
 impl Departure {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(destination: String, scheduled_time: DateTime<Utc>, expected_time: Option<DateTime<Utc>>, calling_points: Vec<String>, platform: Option<u8>, status: String, delay_reason: Option<String>, operator: String) -> Self {
-        Self { destination, scheduled_time, expected_time, calling_points, platform, status, delay_reason, operator }
+        Self { destination, scheduled_time, expected_time, calling_points, platform, status, delay_reason, operator, service_id: None }
     }
 
     pub fn summarise_to_string(&self) -> String {
-        format!(
+        self.summarise_to_string_in(&Utc)
+    }
+
+    pub fn summarise_to_string_in<Tz: TimeZone>(&self, timezone: &Tz) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let mut summary = String::new();
+        self.write_summary_in(&mut summary, timezone).expect("writing to a String cannot fail");
+        summary
+    }
+
+    pub fn write_summary_in<W: Write, Tz: TimeZone>(&self, out: &mut W, timezone: &Tz) -> fmt::Result
+    where
+        Tz::Offset: Display,
+    {
+        write!(
+            out,
             "Destination: {}\n\
             ETA: {}",
             self.destination,
-            self.eta()
+            self.eta().with_timezone(timezone)
         )
     }
 
+    pub fn summarise_to_string_relative(&self, now: DateTime<Utc>) -> String {
+        let mut summary = String::new();
+        self.write_summary_relative(&mut summary, now).expect("writing to a String cannot fail");
+        summary
+    }
+
+    pub fn write_summary_relative<W: Write>(&self, out: &mut W, now: DateTime<Utc>) -> fmt::Result {
+        write!(out, "Destination: {}\nETA: ", self.destination)?;
+        self.write_relative_eta(out, now)
+    }
+
+    pub fn relative_eta(&self, now: DateTime<Utc>) -> String {
+        let mut eta = String::new();
+        self.write_relative_eta(&mut eta, now).expect("writing to a String cannot fail");
+        eta
+    }
+
+    pub fn write_relative_eta<W: Write>(&self, out: &mut W, now: DateTime<Utc>) -> fmt::Result {
+        match (*self.eta() - now).num_minutes() {
+            minutes if minutes > 0 => write!(out, "in {} min", minutes)?,
+            0 => out.write_str("due now")?,
+            minutes => write!(out, "{} min ago", -minutes)?,
+        }
+        match self.delay().num_minutes() {
+            late if late > 0 => write!(out, ", {} min late", late),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern.trim();
+        contains_ignore_case(&self.destination, pattern)
+            || contains_ignore_case(&self.operator, pattern)
+            || self.platform.is_some_and(|platform| {
+                let (digits, start) = platform_digits(platform);
+                contains_ignore_case(std::str::from_utf8(&digits[start..]).expect("platform digits are ASCII"), pattern)
+            })
+    }
+
+    pub fn is_same_service(&self, other: &Departure) -> bool {
+        match (&self.service_id, &other.service_id) {
+            (Some(service_id), Some(other_service_id)) => service_id == other_service_id,
+            _ => self.destination == other.destination && self.scheduled_time == other.scheduled_time,
+        }
+    }
+
     pub fn destination(&self) -> &str {
         &self.destination
     }
@@ -41,13 +120,16 @@ impl Departure {
     }
 
     pub fn eta(&self) -> &DateTime<Utc> {
-        if self.expected_time.is_some() {
-            &self.expected_time.unwrap()
-        } else {
-            &self.scheduled_time
+        match &self.expected_time {
+            Some(expected_time) => expected_time,
+            None => &self.scheduled_time,
         }
     }
 
+    pub fn delay(&self) -> Duration {
+        *self.eta() - self.scheduled_time
+    }
+
     pub fn calling_points(&self) -> &Vec<String> {
         &self.calling_points
     }
@@ -64,10 +146,26 @@ impl Departure {
         &self.delay_reason
     }
 
+    pub fn is_cancelled(&self) -> bool {
+        self.status.eq_ignore_ascii_case("cancelled")
+    }
+
     pub fn operator(&self) -> &str {
         &self.operator
     }
 
+    pub fn operator_info(&self) -> Option<&'static OperatorInfo> {
+        operator::lookup(&self.operator)
+    }
+
+    pub fn service_id(&self) -> &Option<String> {
+        &self.service_id
+    }
+
+    pub fn set_service_id(&mut self, service_id: Option<String>) {
+        self.service_id = service_id;
+    }
+
     pub fn set_expected_time(&mut self, expected_time: Option<DateTime<Utc>>) {
         self.expected_time = expected_time;
     }
@@ -83,4 +181,28 @@ impl Departure {
     pub fn set_delay_reason(&mut self, delay_reason: Option<String>) {
         self.delay_reason = delay_reason;
     }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    needle.is_empty() || haystack.char_indices().any(|(index, _)| starts_with_ignore_case(&haystack[index..], needle))
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    prefix.chars().flat_map(char::to_lowercase).all(|expected| text.next() == Some(expected))
+}
+
+fn platform_digits(platform: u8) -> ([u8; 3], usize) {
+    let mut digits = [b'0'; 3];
+    let mut remaining = platform;
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + remaining % 10;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+    (digits, start)
 }
\ No newline at end of file
@@ -1,31 +1,451 @@
+use std::fmt::{
+    self,
+    Write,
+};
+use std::env;
+use std::fs::{
+    self,
+    File,
+};
+use std::io::{
+    self,
+    Write as _,
+};
+use std::path::Path;
+use std::process::{
+    self,
+    Command,
+    Stdio,
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use chrono::{
+    DateTime,
+    Duration,
+    NaiveDateTime,
+    NaiveTime,
+    Utc,
+};
+use chrono_tz::Tz;
+
+use crate::board_event::{
+    field_changes,
+    BoardEvent,
+};
 use crate::departure::Departure;
+use crate::destination_summary::summarise_destinations;
+use crate::nrcc_message::NrccMessage;
+use crate::ranking::{
+    Ranker as _,
+    RankingStrategy,
+};
+use crate::template::{
+    self,
+    TemplateError,
+};
+use crate::times::BOARD_TIMEZONE;
+use crate::usual_train::UsualTrain;
+
+const DEPARTURE_GRACE_MINUTES: i64 = 2;
+const DEFAULT_PAGER: &str = "less";
+
+static TEMPORARY_FILES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DedupeStrategy {
+    #[default]
+    Dedupe,
+    KeepBoth,
+}
 
 pub struct DepartureBoard {
     departures: Vec<Departure>,
+    requested_rows: Option<usize>,
+    timezone: Tz,
+    relative_times: bool,
+    usual_trains: Vec<UsualTrain>,
+    bucket: Option<Duration>,
+    quiet: bool,
+    search: Option<String>,
+    ranking: RankingStrategy,
+    messages: Vec<NrccMessage>,
+    show_messages: bool,
+}
+
+impl Default for DepartureBoard {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DepartureBoard {
     pub fn new() -> Self {
-        Self { departures: Vec::new() }
+        Self { departures: Vec::new(), requested_rows: None, timezone: BOARD_TIMEZONE, relative_times: false, usual_trains: Vec::new(), bucket: None, quiet: false, search: None, ranking: RankingStrategy::default(), messages: Vec::new(), show_messages: true }
+    }
+
+    pub fn with_requested_rows(requested_rows: usize) -> Self {
+        Self { requested_rows: Some(requested_rows), ..Self::new() }
+    }
+
+    pub fn departures(&self) -> &Vec<Departure> {
+        &self.departures
+    }
+
+    pub fn requested_rows(&self) -> &Option<usize> {
+        &self.requested_rows
+    }
+
+    pub fn set_requested_rows(&mut self, requested_rows: Option<usize>) {
+        self.requested_rows = requested_rows;
+    }
+
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+
+    pub fn set_timezone(&mut self, timezone: Tz) {
+        self.timezone = timezone;
+        self.rank()
+    }
+
+    pub fn relative_times(&self) -> bool {
+        self.relative_times
+    }
+
+    pub fn set_relative_times(&mut self, relative_times: bool) {
+        self.relative_times = relative_times;
+    }
+
+    pub fn bucket(&self) -> &Option<Duration> {
+        &self.bucket
+    }
+
+    pub fn set_bucket(&mut self, bucket: Option<Duration>) {
+        self.bucket = bucket.filter(|bucket| bucket.num_minutes() > 0);
+        self.rank()
+    }
+
+    pub fn quiet(&self) -> bool {
+        self.quiet
+    }
+
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    pub fn search(&self) -> &Option<String> {
+        &self.search
+    }
+
+    pub fn set_search(&mut self, search: Option<String>) {
+        self.search = search.map(|search| search.trim().to_string()).filter(|search| !search.is_empty());
+    }
+
+    pub fn matching(&self, pattern: &str) -> Vec<&Departure> {
+        self.departures.iter().filter(|departure| departure.matches(pattern)).collect()
+    }
+
+    pub fn ranking(&self) -> &RankingStrategy {
+        &self.ranking
+    }
+
+    pub fn set_ranking(&mut self, ranking: RankingStrategy) {
+        self.ranking = ranking;
+        self.rank()
+    }
+
+    pub fn messages(&self) -> &Vec<NrccMessage> {
+        &self.messages
+    }
+
+    pub fn set_messages(&mut self, messages: Vec<NrccMessage>) {
+        self.messages = messages;
+    }
+
+    pub fn show_messages(&self) -> bool {
+        self.show_messages
+    }
+
+    pub fn set_show_messages(&mut self, show_messages: bool) {
+        self.show_messages = show_messages;
+    }
+
+    pub fn usual_trains(&self) -> &Vec<UsualTrain> {
+        &self.usual_trains
+    }
+
+    pub fn add_usual_train(&mut self, usual_train: UsualTrain) {
+        self.usual_trains.push(usual_train);
+    }
+
+    pub fn is_usual_train(&self, departure: &Departure) -> bool {
+        self.usual_trains.iter().any(|usual_train| usual_train.matches(departure))
+    }
+
+    pub fn shows_all_services(&self) -> bool {
+        match self.requested_rows {
+            Some(requested_rows) => self.visible_departures().count() < requested_rows,
+            None => true,
+        }
     }
 
     pub fn add_departure(&mut self, departure: Departure) {
         self.departures.push(departure);
-        self.sort_by_eta()
+        self.rank()
     }
 
     pub fn remove_departure(&mut self, index: usize) {
         self.departures.remove(index);
-        self.sort_by_eta()
+        self.rank()
+    }
+
+    pub fn merge(&mut self, other: DepartureBoard, strategy: DedupeStrategy) {
+        for departure in other.departures {
+            let duplicate = self.departures.iter().any(|existing| existing.is_same_service(&departure));
+            if strategy == DedupeStrategy::KeepBoth || !duplicate {
+                self.departures.push(departure);
+            }
+        }
+        self.rank()
+    }
+
+    pub fn refresh(&mut self, departures: Vec<Departure>, now: DateTime<Utc>) -> Vec<BoardEvent> {
+        let mut events = Vec::new();
+        for previous in self.departures.iter() {
+            if !departures.iter().any(|current| current.is_same_service(previous)) {
+                events.push(Self::disappearance_event(previous, now));
+            }
+        }
+        for current in departures.iter() {
+            match self.departures.iter().find(|previous| previous.is_same_service(current)) {
+                Some(previous) => {
+                    let changes = field_changes(previous, current);
+                    if !changes.is_empty() {
+                        events.push(BoardEvent::ServiceUpdated { departure: current.clone(), changes });
+                    }
+                }
+                None => events.push(BoardEvent::ServiceAdded(current.clone())),
+            }
+        }
+        self.departures = departures;
+        self.rank();
+        events.push(BoardEvent::BoardRefreshed { services: self.departures.len() });
+        events
     }
 
     pub fn print_departures(&self) {
-        for departure in self.departures.iter() {
-            println!("{}\n", departure.summarise_to_string())
+        let mut buffer = String::new();
+        self.render_departures(&mut buffer).expect("writing to a String cannot fail");
+        print!("{}", buffer)
+    }
+
+    pub fn print_summary(&self) {
+        let mut buffer = String::new();
+        self.render_summary(&mut buffer).expect("writing to a String cannot fail");
+        print!("{}", buffer)
+    }
+
+    pub fn render_summary<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for summary in summarise_destinations(self.visible_departures()) {
+            let trains = if summary.count() == 1 { "train" } else { "trains" };
+            write!(
+                out,
+                "{}: {} {}, next {}",
+                summary.destination(),
+                summary.count(),
+                trains,
+                summary.next().with_timezone(&self.timezone).format("%H:%M")
+            )?;
+            if let Some(headway) = summary.headway() {
+                write!(out, ", then every ~{} min", headway.num_minutes())?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    pub fn print_headways(&self) {
+        let mut buffer = String::new();
+        self.render_headways(&mut buffer).expect("writing to a String cannot fail");
+        print!("{}", buffer)
+    }
+
+    pub fn render_headways<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for summary in summarise_destinations(self.visible_departures()) {
+            if let Some(headway) = summary.headway() {
+                writeln!(out, "{}: about every {} min", summary.destination(), headway.num_minutes())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn format_first(&self, template: &str) -> Result<Option<String>, TemplateError> {
+        template::validate(template)?;
+        self.visible_departures().next().map(|departure| template::render(template, departure, &self.timezone)).transpose()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        self.render_markdown(&mut markdown).expect("writing to a String cannot fail");
+        markdown
+    }
+
+    pub fn render_markdown<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let rows = self.requested_rows.unwrap_or(self.departures.len());
+        writeln!(out, "| Scheduled | Destination | Platform | Expected | Status | Operator |")?;
+        writeln!(out, "| --- | --- | --- | --- | --- | --- |")?;
+        for departure in self.visible_departures().take(rows) {
+            let expected_time = match departure.expected_time() {
+                Some(expected_time) => expected_time.with_timezone(&self.timezone).format("%H:%M").to_string(),
+                None => "-".to_string(),
+            };
+            let platform = match departure.platform() {
+                Some(platform) => platform.to_string(),
+                None => "-".to_string(),
+            };
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                departure.scheduled_time().with_timezone(&self.timezone).format("%H:%M"),
+                escape_markdown(departure.destination()),
+                platform,
+                expected_time,
+                escape_markdown(departure.status()),
+                escape_markdown(departure.operator())
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> io::Result<()> {
+        let mut buffer = String::new();
+        self.render_departures(&mut buffer).expect("writing to a String cannot fail");
+        let file_name = path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+        let unique = TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed);
+        let temporary_path = path.with_file_name(format!(".{}.{}.{}.tmp", file_name.to_string_lossy(), process::id(), unique));
+        let written = write_synced(&temporary_path, &buffer).and_then(|()| fs::rename(&temporary_path, path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary_path);
+        }
+        written
+    }
+
+    pub fn render_departures<W: Write>(&self, out: &mut W) -> fmt::Result {
+        if self.show_messages && !self.quiet {
+            for message in self.messages.iter() {
+                writeln!(out, "{}\n", message.text())?;
+            }
+        }
+        self.render_rows(out, self.requested_rows.unwrap_or(self.departures.len()))?;
+        if !self.quiet && self.requested_rows.is_some() && self.shows_all_services() {
+            let services = self.visible_departures().count();
+            let noun = if services == 1 { "service" } else { "services" };
+            writeln!(out, "Showing all {} {}", services, noun)?;
+        }
+        Ok(())
+    }
+
+    pub fn page(&self) -> io::Result<()> {
+        let pager = env::var("PAGER").unwrap_or_else(|_| DEFAULT_PAGER.to_string());
+        self.page_with(&pager)
+    }
+
+    pub fn page_with(&self, pager: &str) -> io::Result<()> {
+        let mut buffer = String::new();
+        self.render_rows(&mut buffer, self.departures.len()).expect("writing to a String cannot fail");
+        let mut arguments = pager.split_whitespace();
+        let program = arguments.next().unwrap_or(DEFAULT_PAGER);
+        let mut child = Command::new(program).args(arguments).stdin(Stdio::piped()).spawn()?;
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(buffer.as_bytes()),
+            None => Ok(()),
+        };
+        let waited = child.wait();
+        match written {
+            Err(error) if error.kind() != io::ErrorKind::BrokenPipe => Err(error),
+            _ => waited.map(|_| ()),
+        }
+    }
+
+    fn render_rows<W: Write>(&self, out: &mut W, rows: usize) -> fmt::Result {
+        let now = Utc::now();
+        let mut current_bucket = None;
+        for departure in self.visible_departures().take(rows) {
+            if let Some(bucket) = self.bucket {
+                let (start, end) = Self::bucket_range(departure, bucket, &self.timezone);
+                if current_bucket != Some(start) {
+                    writeln!(out, "{}–{}\n", start.format("%H:%M"), end.format("%H:%M"))?;
+                    current_bucket = Some(start);
+                }
+            }
+            if self.is_usual_train(departure) {
+                out.write_str("Usual train\n")?;
+            }
+            if self.relative_times {
+                departure.write_summary_relative(out, now)?;
+            } else {
+                departure.write_summary_in(out, &self.timezone)?;
+            }
+            out.write_str("\n\n")?;
         }
+        Ok(())
     }
 
-    fn sort_by_eta(&mut self) {
-        self.departures.sort_by(|a, b| a.eta().timestamp().cmp(&b.eta().timestamp()))
+    fn visible_departures(&self) -> impl Iterator<Item = &Departure> {
+        self.departures.iter().filter(move |departure| match &self.search {
+            Some(search) => departure.matches(search),
+            None => true,
+        })
+    }
+
+    fn bucket_range(departure: &Departure, bucket: Duration, timezone: &Tz) -> (NaiveDateTime, NaiveDateTime) {
+        let eta = departure.eta().with_timezone(timezone).naive_local();
+        let midnight = eta.date().and_time(NaiveTime::MIN);
+        let bucket_minutes = bucket.num_minutes();
+        let minutes = (eta - midnight).num_minutes();
+        let start = midnight + Duration::minutes(minutes - minutes % bucket_minutes);
+        (start, start + bucket - Duration::minutes(1))
+    }
+
+    fn disappearance_event(departure: &Departure, now: DateTime<Utc>) -> BoardEvent {
+        if departure.is_cancelled() {
+            BoardEvent::ServiceCancelled(departure.clone())
+        } else if now >= *departure.eta() - Duration::minutes(DEPARTURE_GRACE_MINUTES) {
+            BoardEvent::ServiceDeparted(departure.clone())
+        } else {
+            BoardEvent::ServiceRemoved(departure.clone())
+        }
+    }
+
+    fn rank(&mut self) {
+        self.ranking.sort(&mut self.departures);
+        if let Some(bucket) = self.bucket {
+            let timezone = self.timezone;
+            self.departures.sort_by_key(|departure| Self::bucket_range(departure, bucket, &timezone).0);
+        }
+    }
+}
+
+fn write_synced(path: &Path, contents: &str) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()
+}
+
+fn escape_markdown(cell: &str) -> String {
+    let mut escaped = String::with_capacity(cell.len());
+    for character in cell.chars() {
+        match character {
+            '\r' | '\n' => escaped.push(' '),
+            '\\' | '|' => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            character => escaped.push(character),
+        }
     }
+    escaped
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
+
+use crate::departure::Departure;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DestinationSummary {
+    destination: String,
+    etas: Vec<DateTime<Utc>>,
+}
+
+impl DestinationSummary {
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    pub fn count(&self) -> usize {
+        self.etas.len()
+    }
+
+    pub fn next(&self) -> &DateTime<Utc> {
+        &self.etas[0]
+    }
+
+    pub fn headway(&self) -> Option<Duration> {
+        let mut gaps: Vec<Duration> = self.etas.windows(2).map(|pair| pair[1] - pair[0]).filter(|gap| *gap > Duration::zero()).collect();
+        if gaps.is_empty() {
+            return None;
+        }
+        gaps.sort();
+        let middle = gaps.len() / 2;
+        let median = if gaps.len().is_multiple_of(2) { (gaps[middle - 1] + gaps[middle]) / 2 } else { gaps[middle] };
+        Some(Duration::minutes((median.num_seconds() + 30) / 60)).filter(|headway| headway.num_minutes() >= 1)
+    }
+}
+
+pub fn summarise_destinations<'a, I>(departures: I) -> Vec<DestinationSummary>
+where
+    I: IntoIterator<Item = &'a Departure>,
+{
+    let mut summaries: Vec<DestinationSummary> = Vec::new();
+    for departure in departures.into_iter().filter(|departure| !departure.is_cancelled()) {
+        match summaries.iter_mut().find(|summary| summary.destination == departure.destination()) {
+            Some(summary) => summary.etas.push(*departure.eta()),
+            None => summaries.push(DestinationSummary { destination: departure.destination().to_string(), etas: vec![*departure.eta()] }),
+        }
+    }
+    for summary in summaries.iter_mut() {
+        summary.etas.sort();
+    }
+    summaries.sort_by_key(|summary| *summary.next());
+    summaries
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use chrono::Duration;
+
+use crate::station_code::StationCode;
+
+const LONDON_TRANSFER_MINUTES: &[(&str, &str, i64)] = &[
+    ("EUS", "KGX", 15),
+    ("EUS", "STP", 15),
+    ("KGX", "STP", 10),
+    ("LST", "FST", 10),
+    ("WAT", "WAE", 10),
+    ("MYB", "PAD", 20),
+    ("MYB", "EUS", 20),
+    ("BFR", "STP", 20),
+    ("BFR", "LBG", 15),
+    ("CST", "LBG", 15),
+    ("CHX", "WAT", 15),
+    ("PAD", "LST", 30),
+    ("PAD", "EUS", 30),
+    ("PAD", "KGX", 30),
+    ("PAD", "STP", 30),
+    ("PAD", "WAT", 30),
+    ("PAD", "VIC", 30),
+    ("EUS", "LST", 30),
+    ("KGX", "LST", 25),
+    ("STP", "LST", 25),
+    ("EUS", "WAT", 25),
+    ("KGX", "WAT", 30),
+    ("STP", "WAT", 30),
+    ("EUS", "VIC", 25),
+    ("KGX", "VIC", 25),
+    ("STP", "VIC", 25),
+    ("EUS", "LBG", 25),
+    ("KGX", "LBG", 25),
+    ("STP", "LBG", 25),
+    ("VIC", "WAT", 25),
+    ("VIC", "LBG", 25),
+    ("WAT", "LBG", 20),
+    ("LST", "LBG", 25),
+    ("LST", "WAT", 30),
+    ("LST", "VIC", 30),
+];
+
+pub fn transfer_time(from: &StationCode, to: &StationCode) -> Option<Duration> {
+    if from == to {
+        return Some(Duration::zero());
+    }
+    LONDON_TRANSFER_MINUTES
+        .iter()
+        .find(|(a, b, _)| (*a == from.as_str() && *b == to.as_str()) || (*a == to.as_str() && *b == from.as_str()))
+        .map(|(_, _, minutes)| Duration::minutes(*minutes))
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+pub mod board_event;
+pub mod board_filter;
+pub mod calling_point;
+pub mod circuit_breaker;
+pub mod config;
+pub mod departure;
+pub mod departure_board;
+pub mod destination_summary;
+pub mod interchange;
+pub mod notifier;
+pub mod nrcc_message;
+pub mod operator;
+pub mod ranking;
+pub mod secret;
+pub mod station_code;
+pub mod stations;
+pub mod statistics;
+pub mod template;
+pub mod times;
+pub mod trip;
+pub mod usual_train;
\ No newline at end of file
@@ -1,6 +1,27 @@
-mod departure;
-mod departure_board;
+use std::env;
+use std::process::ExitCode;
 
-fn main() {
-    println!("Hello, world!");
-}
+use rusty_rails::stations;
+
+const MAX_STATION_MATCHES: usize = 10;
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    match arguments.split_first() {
+        Some((command, query)) if command == "stations" && !query.is_empty() => {
+            let matches = stations::search(&query.join(" "));
+            if matches.is_empty() {
+                eprintln!("No stations match '{}'", query.join(" "));
+                return ExitCode::FAILURE;
+            }
+            for station in matches.iter().take(MAX_STATION_MATCHES) {
+                println!("{}  {}", station.crs(), station.name());
+            }
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("Usage: rusty_rails stations <query>");
+            ExitCode::from(2)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{
+    self,
+    Display,
+};
+
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
+use chrono_tz::Tz;
+
+use crate::board_event::BoardEvent;
+use crate::departure::Departure;
+use crate::times::BOARD_TIMEZONE;
+
+pub trait Notifier {
+    fn notify(&mut self, event: &BoardEvent) -> Result<(), Box<dyn Error>>;
+
+    fn notify_digest(&mut self, events: &[BoardEvent], _span: Duration) -> Result<(), Box<dyn Error>> {
+        for event in events {
+            self.notify(event)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct ConsoleNotifier {
+    timezone: Tz,
+}
+
+impl ConsoleNotifier {
+    pub fn new(timezone: Tz) -> Self {
+        Self { timezone }
+    }
+
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+}
+
+impl Default for ConsoleNotifier {
+    fn default() -> Self {
+        Self::new(BOARD_TIMEZONE)
+    }
+}
+
+impl Notifier for ConsoleNotifier {
+    fn notify(&mut self, event: &BoardEvent) -> Result<(), Box<dyn Error>> {
+        println!("{}", event.describe_in(&self.timezone));
+        Ok(())
+    }
+
+    fn notify_digest(&mut self, events: &[BoardEvent], span: Duration) -> Result<(), Box<dyn Error>> {
+        println!("{}", digest_summary(events, span));
+        for event in events {
+            println!("  {}", event.describe_in(&self.timezone));
+        }
+        Ok(())
+    }
+}
+
+pub struct DigestNotifier {
+    inner: Box<dyn Notifier>,
+    window: Duration,
+    pending: Vec<BoardEvent>,
+    window_started: Option<DateTime<Utc>>,
+}
+
+impl DigestNotifier {
+    pub fn new(inner: Box<dyn Notifier>, window: Duration) -> Self {
+        Self { inner, window, pending: Vec::new(), window_started: None }
+    }
+
+    pub fn window(&self) -> &Duration {
+        &self.window
+    }
+
+    pub fn pending(&self) -> &Vec<BoardEvent> {
+        &self.pending
+    }
+
+    pub fn notify_at(&mut self, event: &BoardEvent, now: DateTime<Utc>) -> Result<(), Box<dyn Error>> {
+        if let BoardEvent::BoardRefreshed { .. } = event {
+            return self.flush_if_due(now);
+        }
+        self.pending.push(event.clone());
+        self.window_started.get_or_insert(now);
+        self.flush_if_due(now)
+    }
+
+    pub fn flush_if_due(&mut self, now: DateTime<Utc>) -> Result<(), Box<dyn Error>> {
+        match self.window_started {
+            Some(window_started) if now >= window_started + self.window => self.flush_at(now),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        self.flush_at(Utc::now())
+    }
+
+    pub fn flush_at(&mut self, now: DateTime<Utc>) -> Result<(), Box<dyn Error>> {
+        let span = match self.window_started.take() {
+            Some(window_started) => now - window_started,
+            None => Duration::zero(),
+        };
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let events = std::mem::take(&mut self.pending);
+        self.inner.notify_digest(&events, span)
+    }
+}
+
+impl Notifier for DigestNotifier {
+    fn notify(&mut self, event: &BoardEvent) -> Result<(), Box<dyn Error>> {
+        self.notify_at(event, Utc::now())
+    }
+}
+
+pub fn digest_summary(events: &[BoardEvent], span: Duration) -> String {
+    let mut services: Vec<&Departure> = Vec::new();
+    let mut failures = 0;
+    for event in events {
+        match event.departure() {
+            Some(departure) if !services.iter().any(|service| service.is_same_service(departure)) => services.push(departure),
+            Some(_) => (),
+            None if matches!(event, BoardEvent::FetchFailed { .. }) => failures += 1,
+            None => (),
+        }
+    }
+    let minutes = match span.num_minutes() {
+        minutes if minutes <= 1 => "minute".to_string(),
+        minutes => format!("{} minutes", minutes),
+    };
+    let mut lines = Vec::new();
+    if !services.is_empty() {
+        let noun = if services.len() == 1 { "service" } else { "services" };
+        lines.push(format!("{} {} affected in the last {}", services.len(), noun, minutes));
+    }
+    if failures > 0 {
+        let noun = if failures == 1 { "time" } else { "times" };
+        lines.push(format!("Fetching failed {} {} in the last {}", failures, noun, minutes));
+    }
+    lines.join("\n")
+}
+
+#[derive(Debug)]
+pub enum NotifyError {
+    UnknownNotifier(String),
+    Failed { notifier: String, source: Box<dyn Error> },
+}
+
+impl Display for NotifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotifyError::UnknownNotifier(name) => write!(f, "no notifier named '{}' is registered", name),
+            NotifyError::Failed { notifier, source } => write!(f, "notifier '{}' failed: {}", notifier, source),
+        }
+    }
+}
+
+impl Error for NotifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NotifyError::UnknownNotifier(_) => None,
+            NotifyError::Failed { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct NotifierRegistry {
+    notifiers: HashMap<String, Box<dyn Notifier>>,
+}
+
+impl NotifierRegistry {
+    pub fn new() -> Self {
+        Self { notifiers: HashMap::new() }
+    }
+
+    pub fn register(&mut self, name: String, notifier: Box<dyn Notifier>) {
+        self.notifiers.insert(name, notifier);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.notifiers.contains_key(name)
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.notifiers.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    pub fn notify(&mut self, name: &str, event: &BoardEvent) -> Result<(), NotifyError> {
+        let notifier = self.notifiers.get_mut(name).ok_or_else(|| NotifyError::UnknownNotifier(name.to_string()))?;
+        notifier.notify(event).map_err(|source| NotifyError::Failed { notifier: name.to_string(), source })
+    }
+}
\ No newline at end of file
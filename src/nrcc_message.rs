@@ -0,0 +1,78 @@
+use serde::Deserialize;
+
+const BLOCK_TAGS: &[&str] = &["p", "br", "div", "li", "ul", "ol", "tr", "h1", "h2", "h3"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(from = "RawNrccMessage")]
+pub struct NrccMessage {
+    value: String,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct RawNrccMessage {
+    #[serde(rename = "Value", alias = "value")]
+    value: String,
+}
+
+impl NrccMessage {
+    pub fn new(value: String) -> Self {
+        let text = strip_html(&value);
+        Self { value, text }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<RawNrccMessage> for NrccMessage {
+    fn from(raw: RawNrccMessage) -> Self {
+        Self::new(raw.value)
+    }
+}
+
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+    let mut characters = html.chars().peekable();
+    while let Some(character) = characters.next() {
+        match (&mut tag, character) {
+            (None, '<') if characters.peek().is_some_and(|next| opens_tag(*next)) => tag = Some(String::new()),
+            (Some(name), '>') => {
+                if is_block_tag(name) {
+                    text.push(' ');
+                }
+                tag = None;
+            }
+            (Some(name), character) => name.push(character),
+            (None, character) => text.push(character),
+        }
+    }
+    if let Some(unclosed) = tag {
+        text.push('<');
+        text.push_str(&unclosed);
+    }
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn opens_tag(character: char) -> bool {
+    character.is_ascii_alphabetic() || character == '/' || character == '!'
+}
+
+fn is_block_tag(tag: &str) -> bool {
+    let name = tag.trim_start_matches('/').split(|character: char| character.is_whitespace() || character == '/').next().unwrap_or("");
+    BLOCK_TAGS.iter().any(|block_tag| block_tag.eq_ignore_ascii_case(name))
+}
\ No newline at end of file
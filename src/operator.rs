@@ -0,0 +1,66 @@
+#[derive(Debug, PartialEq, Eq)]
+pub struct OperatorInfo {
+    code: &'static str,
+    name: &'static str,
+    website: &'static str,
+    social_handle: &'static str,
+}
+
+impl OperatorInfo {
+    pub fn code(&self) -> &str {
+        self.code
+    }
+
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    pub fn website(&self) -> &str {
+        self.website
+    }
+
+    pub fn social_handle(&self) -> &str {
+        self.social_handle
+    }
+}
+
+const fn operator(code: &'static str, name: &'static str, website: &'static str, social_handle: &'static str) -> OperatorInfo {
+    OperatorInfo { code, name, website, social_handle }
+}
+
+pub const OPERATORS: &[OperatorInfo] = &[
+    operator("AW", "Transport for Wales", "https://tfw.wales", "@tfwrail"),
+    operator("CC", "c2c", "https://www.c2c-online.co.uk", "@c2c_Rail"),
+    operator("CH", "Chiltern Railways", "https://www.chilternrailways.co.uk", "@chilternrailway"),
+    operator("CS", "Caledonian Sleeper", "https://www.sleeper.scot", "@CalSleeper"),
+    operator("EM", "East Midlands Railway", "https://www.eastmidlandsrailway.co.uk", "@EastMidRailway"),
+    operator("GC", "Grand Central", "https://www.grandcentralrail.com", "@GC_Rail"),
+    operator("GN", "Great Northern", "https://www.greatnorthernrail.com", "@GNRailUK"),
+    operator("GR", "LNER", "https://www.lner.co.uk", "@LNER"),
+    operator("GW", "Great Western Railway", "https://www.gwr.com", "@GWRHelp"),
+    operator("GX", "Gatwick Express", "https://www.gatwickexpress.com", "@GatwickExpress"),
+    operator("HT", "Hull Trains", "https://www.hulltrains.co.uk", "@Hull_Trains"),
+    operator("HX", "Heathrow Express", "https://www.heathrowexpress.com", "@HeathrowExpress"),
+    operator("LD", "Lumo", "https://www.lumo.co.uk", "@LumoTravel"),
+    operator("LE", "Greater Anglia", "https://www.greateranglia.co.uk", "@greateranglia"),
+    operator("LM", "West Midlands Railway", "https://www.westmidlandsrailway.co.uk", "@WestMidRailway"),
+    operator("LO", "London Overground", "https://tfl.gov.uk/modes/london-overground", "@LDNOverground"),
+    operator("ME", "Merseyrail", "https://www.merseyrail.org", "@merseyrail"),
+    operator("NT", "Northern", "https://www.northernrailway.co.uk", "@northernassist"),
+    operator("SE", "Southeastern", "https://www.southeasternrailway.co.uk", "@Se_Railway"),
+    operator("SN", "Southern", "https://www.southernrailway.com", "@SouthernRailUK"),
+    operator("SR", "ScotRail", "https://www.scotrail.co.uk", "@ScotRail"),
+    operator("SW", "South Western Railway", "https://www.southwesternrailway.com", "@SW_Help"),
+    operator("TL", "Thameslink", "https://www.thameslinkrailway.com", "@TLRailUK"),
+    operator("TP", "TransPennine Express", "https://www.tpexpress.co.uk", "@TPExpressTrains"),
+    operator("VT", "Avanti West Coast", "https://www.avantiwestcoast.co.uk", "@AvantiWestCoast"),
+    operator("XC", "CrossCountry", "https://www.crosscountrytrains.co.uk", "@CrossCountryUK"),
+    operator("XR", "Elizabeth line", "https://tfl.gov.uk/modes/elizabeth-line", "@TheElizabethLine"),
+];
+
+pub fn lookup(name_or_code: &str) -> Option<&'static OperatorInfo> {
+    let name_or_code = name_or_code.trim();
+    OPERATORS
+        .iter()
+        .find(|operator| operator.code.eq_ignore_ascii_case(name_or_code) || operator.name.eq_ignore_ascii_case(name_or_code))
+}
\ No newline at end of file
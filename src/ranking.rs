@@ -0,0 +1,79 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::departure::Departure;
+
+pub trait Ranker {
+    fn compare(&self, a: &Departure, b: &Departure) -> Ordering;
+
+    fn sort(&self, departures: &mut [Departure]) {
+        departures.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+impl<F> Ranker for F
+where
+    F: Fn(&Departure, &Departure) -> Ordering,
+{
+    fn compare(&self, a: &Departure, b: &Departure) -> Ordering {
+        self(a, b)
+    }
+}
+
+#[derive(Clone, Default)]
+pub enum RankingStrategy {
+    #[default]
+    Soonest,
+    LeastDelayed,
+    BestForDestination(String),
+    Custom(Arc<dyn Ranker>),
+}
+
+impl RankingStrategy {
+    pub fn custom<R: Ranker + 'static>(ranker: R) -> Self {
+        RankingStrategy::Custom(Arc::new(ranker))
+    }
+}
+
+impl Ranker for RankingStrategy {
+    fn compare(&self, a: &Departure, b: &Departure) -> Ordering {
+        match self {
+            RankingStrategy::Soonest => a.eta().cmp(b.eta()),
+            RankingStrategy::LeastDelayed => (a.is_cancelled(), a.delay(), *a.eta()).cmp(&(b.is_cancelled(), b.delay(), *b.eta())),
+            RankingStrategy::BestForDestination(destination) => {
+                (!serves(a, destination), a.is_cancelled(), *a.eta()).cmp(&(!serves(b, destination), b.is_cancelled(), *b.eta()))
+            }
+            RankingStrategy::Custom(ranker) => ranker.compare(a, b),
+        }
+    }
+}
+
+impl fmt::Debug for RankingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RankingStrategy::Soonest => f.write_str("Soonest"),
+            RankingStrategy::LeastDelayed => f.write_str("LeastDelayed"),
+            RankingStrategy::BestForDestination(destination) => f.debug_tuple("BestForDestination").field(destination).finish(),
+            RankingStrategy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for RankingStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RankingStrategy::Soonest, RankingStrategy::Soonest) | (RankingStrategy::LeastDelayed, RankingStrategy::LeastDelayed) => true,
+            (RankingStrategy::BestForDestination(a), RankingStrategy::BestForDestination(b)) => a == b,
+            (RankingStrategy::Custom(a), RankingStrategy::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RankingStrategy {}
+
+fn serves(departure: &Departure, destination: &str) -> bool {
+    departure.destination().eq_ignore_ascii_case(destination)
+        || departure.calling_points().iter().any(|calling_point| calling_point.eq_ignore_ascii_case(destination))
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt::{
+    self,
+    Display,
+};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StationCode(String);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StationCodeError {
+    Empty,
+    WrongLength { code: String, length: usize },
+    InvalidCharacter { code: String, character: char },
+}
+
+impl StationCode {
+    pub fn new(code: &str) -> Result<Self, StationCodeError> {
+        let code = code.trim();
+        if code.is_empty() {
+            return Err(StationCodeError::Empty);
+        }
+        if let Some(character) = code.chars().find(|character| !character.is_ascii_alphabetic()) {
+            return Err(StationCodeError::InvalidCharacter { code: code.to_string(), character });
+        }
+        if code.len() != 3 {
+            return Err(StationCodeError::WrongLength { code: code.to_string(), length: code.len() });
+        }
+        Ok(Self(code.to_ascii_uppercase()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for StationCode {
+    type Err = StationCodeError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::new(code)
+    }
+}
+
+impl TryFrom<&str> for StationCode {
+    type Error = StationCodeError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        Self::new(code)
+    }
+}
+
+impl AsRef<str> for StationCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for StationCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Display for StationCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StationCodeError::Empty => write!(f, "station code is empty; expected 3 letters such as KGX"),
+            StationCodeError::WrongLength { code, length } => write!(f, "station code '{}' has {} letters; expected exactly 3", code, length),
+            StationCodeError::InvalidCharacter { code, character } => write!(f, "station code '{}' contains '{}'; only the letters A-Z are allowed", code, character),
+        }
+    }
+}
+
+impl Error for StationCodeError {}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use std::error::Error;
+use std::fmt::{
+    self,
+    Display,
+};
+use std::sync::OnceLock;
+
+use crate::station_code::StationCode;
+
+const STATIONS_CSV: &str = include_str!("../data/stations.csv");
+const MINIMUM_RESOLVE_SCORE: u32 = 60;
+const MAXIMUM_LISTED_CANDIDATES: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Station {
+    name: String,
+    crs: StationCode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResolveError {
+    Unknown(String),
+    Ambiguous { query: String, candidates: Vec<Station> },
+}
+
+impl Station {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn crs(&self) -> &StationCode {
+        &self.crs
+    }
+}
+
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::Unknown(query) => write!(f, "'{}' is not a station code or known station name", query),
+            ResolveError::Ambiguous { query, candidates } => {
+                write!(f, "'{}' matches {} stations: ", query, candidates.len())?;
+                for (index, station) in candidates.iter().take(MAXIMUM_LISTED_CANDIDATES).enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{} ({})", station.name, station.crs.as_str())?;
+                }
+                if candidates.len() > MAXIMUM_LISTED_CANDIDATES {
+                    write!(f, " and {} more", candidates.len() - MAXIMUM_LISTED_CANDIDATES)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ResolveError {}
+
+pub fn all() -> &'static [Station] {
+    static STATIONS: OnceLock<Vec<Station>> = OnceLock::new();
+    STATIONS.get_or_init(|| {
+        STATIONS_CSV
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.rsplit_once(','))
+            .map(|(name, crs)| Station {
+                name: name.to_string(),
+                crs: StationCode::new(crs).expect("embedded station dataset contains an invalid CRS code"),
+            })
+            .collect()
+    })
+}
+
+pub fn search(query: &str) -> Vec<&'static Station> {
+    let query = normalise(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<(u32, &Station)> = all().iter().filter_map(|station| score(station, &query).map(|score| (score, station))).collect();
+    matches.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.name.cmp(&b.name)));
+    matches.into_iter().map(|(_, station)| station).collect()
+}
+
+pub fn resolve(name_or_code: &str) -> Result<StationCode, ResolveError> {
+    let query = normalise(name_or_code);
+    if query.is_empty() {
+        return Err(ResolveError::Unknown(name_or_code.trim().to_string()));
+    }
+    if let Some(station) = all().iter().find(|station| station.crs.as_str().eq_ignore_ascii_case(&query)) {
+        return Ok(station.crs.clone());
+    }
+    let matches: Vec<(u32, &Station)> = all()
+        .iter()
+        .filter_map(|station| score(station, &query).map(|score| (score, station)))
+        .filter(|(score, _)| *score >= MINIMUM_RESOLVE_SCORE)
+        .collect();
+    let best = matches.iter().map(|(score, _)| *score).max();
+    let mut candidates: Vec<Station> = matches.into_iter().filter(|(score, _)| Some(*score) == best).map(|(_, station)| station.clone()).collect();
+    match candidates.len() {
+        // The embedded dataset only covers part of the network, so a well-formed code it does not know is passed through.
+        0 => StationCode::new(name_or_code).map_err(|_| ResolveError::Unknown(name_or_code.trim().to_string())),
+        1 => Ok(candidates.remove(0).crs),
+        _ => {
+            candidates.sort_by(|a, b| a.name.cmp(&b.name));
+            Err(ResolveError::Ambiguous { query: name_or_code.trim().to_string(), candidates })
+        }
+    }
+}
+
+fn score(station: &Station, query: &str) -> Option<u32> {
+    let name = normalise(&station.name);
+    if station.crs.as_str().eq_ignore_ascii_case(query) {
+        Some(100)
+    } else if name == query {
+        Some(90)
+    } else if name.starts_with(query) {
+        Some(80)
+    } else if name.split(' ').any(|word| word.starts_with(query)) {
+        Some(70)
+    } else if name.contains(query) {
+        Some(60)
+    } else if is_subsequence(query, &name) {
+        Some(40)
+    } else {
+        None
+    }
+}
+
+fn is_subsequence(query: &str, name: &str) -> bool {
+    let mut name = name.chars();
+    query.chars().filter(|character| *character != ' ').all(|character| name.any(|candidate| candidate == character))
+}
+
+fn normalise(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|character| character.is_alphanumeric() || character.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
\ No newline at end of file
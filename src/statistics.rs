@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
+
+use crate::board_event::BoardEvent;
+use crate::departure::Departure;
+
+const DEFAULT_WINDOW_HOURS: i64 = 24;
+
+struct Observation {
+    operator: String,
+    destination: String,
+    delay: Duration,
+    cancelled: bool,
+    observed_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceStatistics {
+    services: usize,
+    cancellations: usize,
+    total_delay: Duration,
+}
+
+impl ServiceStatistics {
+    fn new() -> Self {
+        Self { services: 0, cancellations: 0, total_delay: Duration::zero() }
+    }
+
+    pub fn services(&self) -> usize {
+        self.services
+    }
+
+    pub fn cancellations(&self) -> usize {
+        self.cancellations
+    }
+
+    pub fn average_delay(&self) -> Duration {
+        let running = self.services - self.cancellations;
+        if running == 0 {
+            Duration::zero()
+        } else {
+            self.total_delay / running as i32
+        }
+    }
+}
+
+pub struct Statistics {
+    observations: Vec<Observation>,
+    window: Duration,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Self::with_window(Duration::hours(DEFAULT_WINDOW_HOURS))
+    }
+
+    pub fn with_window(window: Duration) -> Self {
+        Self { observations: Vec::new(), window }
+    }
+
+    pub fn window(&self) -> &Duration {
+        &self.window
+    }
+
+    pub fn record(&mut self, departure: &Departure, observed_at: DateTime<Utc>) {
+        self.observations.push(Observation {
+            operator: departure.operator().to_string(),
+            destination: departure.destination().to_string(),
+            delay: departure.delay(),
+            cancelled: departure.is_cancelled(),
+            observed_at,
+        });
+        self.prune(observed_at)
+    }
+
+    pub fn record_event(&mut self, event: &BoardEvent, observed_at: DateTime<Utc>) {
+        match event {
+            BoardEvent::ServiceDeparted(departure) | BoardEvent::ServiceCancelled(departure) => self.record(departure, observed_at),
+            _ => (),
+        }
+    }
+
+    pub fn prune(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - self.window;
+        self.observations.retain(|observation| observation.observed_at > cutoff)
+    }
+
+    pub fn by_operator(&self, now: DateTime<Utc>) -> HashMap<String, ServiceStatistics> {
+        self.aggregate(now, |observation| &observation.operator)
+    }
+
+    pub fn by_destination(&self, now: DateTime<Utc>) -> HashMap<String, ServiceStatistics> {
+        self.aggregate(now, |observation| &observation.destination)
+    }
+
+    fn aggregate<F>(&self, now: DateTime<Utc>, key: F) -> HashMap<String, ServiceStatistics>
+    where
+        F: Fn(&Observation) -> &String,
+    {
+        let cutoff = now - self.window;
+        let mut statistics: HashMap<String, ServiceStatistics> = HashMap::new();
+        for observation in self.observations.iter().filter(|observation| observation.observed_at > cutoff) {
+            let entry = statistics.entry(key(observation).clone()).or_insert_with(ServiceStatistics::new);
+            entry.services += 1;
+            if observation.cancelled {
+                entry.cancellations += 1;
+            } else {
+                entry.total_delay += observation.delay;
+            }
+        }
+        statistics
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use std::error::Error;
+use std::fmt::{
+    self,
+    Display,
+    Write,
+};
+
+use chrono_tz::Tz;
+
+use crate::departure::Departure;
+
+pub const FIELDS: &[&str] = &["destination", "std", "etd", "eta", "platform", "status", "delay", "reason", "operator", "service_id"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    UnknownField(String),
+    UnclosedBrace,
+    UnmatchedClosingBrace,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnknownField(field) => write!(f, "unknown template field '{{{}}}'; expected one of {}", field, FIELDS.join(", ")),
+            TemplateError::UnclosedBrace => write!(f, "template has a '{{' without a matching '}}'; use '{{{{' for a literal brace"),
+            TemplateError::UnmatchedClosingBrace => write!(f, "template has a '}}' without a matching '{{'; use '}}}}' for a literal brace"),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+enum Piece<'a> {
+    Literal(char),
+    Field(&'a str),
+}
+
+pub fn validate(template: &str) -> Result<(), TemplateError> {
+    parse(template, |piece| match piece {
+        Piece::Field(field) if !FIELDS.contains(&field) => Err(TemplateError::UnknownField(field.to_string())),
+        _ => Ok(()),
+    })
+}
+
+pub fn render(template: &str, departure: &Departure, timezone: &Tz) -> Result<String, TemplateError> {
+    let mut output = String::new();
+    parse(template, |piece| match piece {
+        Piece::Literal(character) => {
+            output.push(character);
+            Ok(())
+        }
+        Piece::Field(field) => write_field(&mut output, field, departure, timezone),
+    })?;
+    Ok(output)
+}
+
+fn parse<'a, F>(template: &'a str, mut visit: F) -> Result<(), TemplateError>
+where
+    F: FnMut(Piece<'a>) -> Result<(), TemplateError>,
+{
+    let mut characters = template.char_indices().peekable();
+    while let Some((index, character)) = characters.next() {
+        match character {
+            '{' if characters.peek().map(|&(_, next)| next) == Some('{') => {
+                characters.next();
+                visit(Piece::Literal('{'))?;
+            }
+            '{' => {
+                let start = index + 1;
+                let end = loop {
+                    match characters.next() {
+                        Some((end, '}')) => break end,
+                        Some(_) => (),
+                        None => return Err(TemplateError::UnclosedBrace),
+                    }
+                };
+                visit(Piece::Field(template[start..end].trim()))?;
+            }
+            '}' if characters.peek().map(|&(_, next)| next) == Some('}') => {
+                characters.next();
+                visit(Piece::Literal('}'))?;
+            }
+            '}' => return Err(TemplateError::UnmatchedClosingBrace),
+            character => visit(Piece::Literal(character))?,
+        }
+    }
+    Ok(())
+}
+
+fn write_field(output: &mut String, field: &str, departure: &Departure, timezone: &Tz) -> Result<(), TemplateError> {
+    let result = match field {
+        "destination" => output.write_str(departure.destination()),
+        "std" => write!(output, "{}", departure.scheduled_time().with_timezone(timezone).format("%H:%M")),
+        "etd" if departure.is_cancelled() => output.write_str("Cancelled"),
+        "etd" => match departure.expected_time() {
+            Some(expected_time) => write!(output, "{}", expected_time.with_timezone(timezone).format("%H:%M")),
+            None if departure.status().is_empty() || departure.status().eq_ignore_ascii_case("on time") => output.write_str("On time"),
+            None => output.write_str(departure.status()),
+        },
+        "eta" => write!(output, "{}", departure.eta().with_timezone(timezone).format("%H:%M")),
+        "platform" => match departure.platform() {
+            Some(platform) => write!(output, "{}", platform),
+            None => output.write_str("-"),
+        },
+        "status" => output.write_str(departure.status()),
+        "delay" => write!(output, "{}", departure.delay().num_minutes()),
+        "reason" => output.write_str(departure.delay_reason().as_deref().unwrap_or("")),
+        "operator" => output.write_str(departure.operator()),
+        "service_id" => output.write_str(departure.service_id().as_deref().unwrap_or("")),
+        field => return Err(TemplateError::UnknownField(field.to_string())),
+    };
+    result.expect("writing to a String cannot fail");
+    Ok(())
+}
\ No newline at end of file
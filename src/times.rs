@@ -0,0 +1,26 @@
+use chrono::{
+    DateTime,
+    Duration,
+    NaiveDateTime,
+    NaiveTime,
+    TimeZone,
+    Utc,
+};
+use chrono_tz::Tz;
+
+pub const BOARD_TIMEZONE: Tz = chrono_tz::Europe::London;
+
+pub fn resolve_time<Tz: TimeZone>(time: &str, now: &DateTime<Tz>) -> Option<DateTime<Utc>> {
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M").ok()?;
+    let now_utc = now.with_timezone(&Utc);
+    let today = now.date_naive();
+    [today.pred_opt()?, today, today.succ_opt()?]
+        .iter()
+        .filter_map(|date| localise(&now.timezone(), date.and_time(time)))
+        .map(|candidate| candidate.with_timezone(&Utc))
+        .min_by_key(|candidate| (*candidate - now_utc).num_seconds().abs())
+}
+
+fn localise<Tz: TimeZone>(timezone: &Tz, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+    timezone.from_local_datetime(&local).earliest().or_else(|| timezone.from_local_datetime(&(local + Duration::hours(1))).earliest())
+}
\ No newline at end of file
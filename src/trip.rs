@@ -0,0 +1,151 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
+
+use crate::interchange;
+use crate::station_code::StationCode;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leg {
+    from: StationCode,
+    to: StationCode,
+    scheduled_departure: DateTime<Utc>,
+    expected_departure: Option<DateTime<Utc>>,
+    scheduled_arrival: DateTime<Utc>,
+    expected_arrival: Option<DateTime<Utc>>,
+    cancelled: bool,
+}
+
+impl Leg {
+    pub fn new(from: StationCode, to: StationCode, scheduled_departure: DateTime<Utc>, scheduled_arrival: DateTime<Utc>) -> Self {
+        Self { from, to, scheduled_departure, expected_departure: None, scheduled_arrival, expected_arrival: None, cancelled: false }
+    }
+
+    pub fn from(&self) -> &StationCode {
+        &self.from
+    }
+
+    pub fn to(&self) -> &StationCode {
+        &self.to
+    }
+
+    pub fn scheduled_departure(&self) -> &DateTime<Utc> {
+        &self.scheduled_departure
+    }
+
+    pub fn scheduled_arrival(&self) -> &DateTime<Utc> {
+        &self.scheduled_arrival
+    }
+
+    pub fn departure_eta(&self) -> &DateTime<Utc> {
+        self.expected_departure.as_ref().unwrap_or(&self.scheduled_departure)
+    }
+
+    pub fn arrival_eta(&self) -> &DateTime<Utc> {
+        self.expected_arrival.as_ref().unwrap_or(&self.scheduled_arrival)
+    }
+
+    pub fn set_expected_departure(&mut self, expected_departure: Option<DateTime<Utc>>) {
+        self.expected_departure = expected_departure;
+    }
+
+    pub fn set_expected_arrival(&mut self, expected_arrival: Option<DateTime<Utc>>) {
+        self.expected_arrival = expected_arrival;
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn set_cancelled(&mut self, cancelled: bool) {
+        self.cancelled = cancelled;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interchange {
+    arriving_at: StationCode,
+    departing_from: StationCode,
+    available: Duration,
+    required: Duration,
+    cancelled: bool,
+}
+
+impl Interchange {
+    pub fn arriving_at(&self) -> &StationCode {
+        &self.arriving_at
+    }
+
+    pub fn departing_from(&self) -> &StationCode {
+        &self.departing_from
+    }
+
+    pub fn available(&self) -> &Duration {
+        &self.available
+    }
+
+    pub fn required(&self) -> &Duration {
+        &self.required
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn is_achievable(&self) -> bool {
+        !self.cancelled && self.available >= self.required
+    }
+}
+
+pub struct Trip {
+    legs: Vec<Leg>,
+    minimum_interchange: Duration,
+}
+
+impl Trip {
+    pub fn new(legs: Vec<Leg>, minimum_interchange: Duration) -> Self {
+        Self { legs, minimum_interchange }
+    }
+
+    pub fn legs(&self) -> &Vec<Leg> {
+        &self.legs
+    }
+
+    pub fn legs_mut(&mut self) -> &mut Vec<Leg> {
+        &mut self.legs
+    }
+
+    pub fn minimum_interchange(&self) -> &Duration {
+        &self.minimum_interchange
+    }
+
+    pub fn interchanges(&self) -> Vec<Interchange> {
+        self.legs
+            .windows(2)
+            .map(|pair| Interchange {
+                arriving_at: pair[0].to.clone(),
+                departing_from: pair[1].from.clone(),
+                available: *pair[1].departure_eta() - *pair[0].arrival_eta(),
+                required: self.required_interchange(&pair[0].to, &pair[1].from),
+                cancelled: pair[0].cancelled || pair[1].cancelled,
+            })
+            .collect()
+    }
+
+    pub fn required_interchange(&self, arriving_at: &StationCode, departing_from: &StationCode) -> Duration {
+        match interchange::transfer_time(arriving_at, departing_from) {
+            Some(transfer_time) => transfer_time.max(self.minimum_interchange),
+            None => self.minimum_interchange,
+        }
+    }
+
+    pub fn broken_interchanges(&self) -> Vec<Interchange> {
+        self.interchanges().into_iter().filter(|interchange| !interchange.is_achievable()).collect()
+    }
+
+    pub fn is_achievable(&self) -> bool {
+        self.legs.iter().all(|leg| !leg.cancelled) && self.interchanges().iter().all(Interchange::is_achievable)
+    }
+}
\ No newline at end of file
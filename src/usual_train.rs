@@ -0,0 +1,29 @@
+use chrono::NaiveTime;
+
+use crate::departure::Departure;
+use crate::times::BOARD_TIMEZONE;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsualTrain {
+    scheduled_time: NaiveTime,
+    destination: String,
+}
+
+impl UsualTrain {
+    pub fn new(scheduled_time: NaiveTime, destination: String) -> Self {
+        Self { scheduled_time, destination }
+    }
+
+    pub fn scheduled_time(&self) -> &NaiveTime {
+        &self.scheduled_time
+    }
+
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    pub fn matches(&self, departure: &Departure) -> bool {
+        departure.scheduled_time().with_timezone(&BOARD_TIMEZONE).time() == self.scheduled_time
+            && departure.destination().eq_ignore_ascii_case(&self.destination)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+mod common;
+
+use common::{
+    at,
+    departure,
+};
+use rusty_rails::board_event::BoardEvent;
+
+#[test]
+fn description_uses_requested_timezone() {
+    let event = BoardEvent::ServiceCancelled(departure("Brighton", at(10, 0), "Cancelled"));
+    assert_eq!(event.describe_in(&chrono_tz::America::New_York), "06:00 Brighton service cancelled");
+    assert_eq!(event.describe_in(&chrono_tz::UTC), "10:00 Brighton service cancelled");
+}
+
+#[test]
+fn display_uses_board_timezone() {
+    let event = BoardEvent::ServiceUpdated { departure: departure("Lewes", at(10, 15), "Delayed"), changes: Vec::new() };
+    assert_eq!(event.to_string(), "11:15 Lewes service updated (0 changes)");
+    assert_eq!(BoardEvent::FetchFailed { reason: "timeout".to_string() }.to_string(), "Fetch failed: timeout");
+}
\ No newline at end of file
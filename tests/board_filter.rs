@@ -0,0 +1,19 @@
+use rusty_rails::board_filter::{
+    BoardFilter,
+    FilterType,
+};
+use rusty_rails::station_code::StationCode;
+
+#[test]
+fn departing_to_filters_by_destination() {
+    let filter = BoardFilter::departing_to(StationCode::new("btn").unwrap());
+    assert_eq!(*filter.filter_type(), FilterType::To);
+    assert_eq!(filter.query_parameters(), [("filterCrs", "BTN"), ("filterType", "to")]);
+}
+
+#[test]
+fn arriving_from_filters_by_origin() {
+    let filter = BoardFilter::arriving_from(StationCode::new("LWS").unwrap());
+    assert_eq!(filter, BoardFilter::new(StationCode::new("LWS").unwrap(), FilterType::From));
+    assert_eq!(filter.query_parameters(), [("filterCrs", "LWS"), ("filterType", "from")]);
+}
\ No newline at end of file
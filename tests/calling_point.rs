@@ -0,0 +1,53 @@
+mod common;
+
+use common::at;
+use rusty_rails::calling_point::CallingPoint;
+
+fn parse(json: &str) -> CallingPoint {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn missing_crs_is_none() {
+    let calling_point = parse(r#"{ "locationName": "Ford (Sussex)", "st": "10:00" }"#);
+    assert_eq!(*calling_point.crs(), None);
+    assert_eq!(calling_point.scheduled().as_deref(), Some("10:00"));
+}
+
+#[test]
+fn null_crs_is_none() {
+    assert_eq!(*parse(r#"{ "locationName": "Ford (Sussex)", "crs": null }"#).crs(), None);
+}
+
+#[test]
+fn empty_crs_is_none() {
+    assert_eq!(*parse(r#"{ "locationName": "Ford (Sussex)", "crs": "" }"#).crs(), None);
+}
+
+#[test]
+fn malformed_crs_is_none() {
+    assert_eq!(*parse(r#"{ "locationName": "Ford (Sussex)", "crs": "FO1D" }"#).crs(), None);
+}
+
+#[test]
+fn valid_crs_is_normalised() {
+    assert_eq!(parse(r#"{ "locationName": "Lewes", "crs": "lws" }"#).crs().as_ref().map(|crs| crs.as_str()), Some("LWS"));
+}
+
+#[test]
+fn display_name_includes_code_only_when_known() {
+    assert_eq!(parse(r#"{ "locationName": "Lewes", "crs": "LWS" }"#).display_name(), "Lewes (LWS)");
+    assert_eq!(parse(r#"{ "locationName": "Ford (Sussex)" }"#).display_name(), "Ford (Sussex)");
+}
+
+#[test]
+fn expected_time_resolves_times_and_status_words() {
+    let now = at(10, 0);
+    let delayed = parse(r#"{ "locationName": "Lewes", "st": "11:10", "et": "11:14" }"#);
+    assert_eq!(delayed.expected_time(now), Some(at(10, 14)));
+    let on_time = parse(r#"{ "locationName": "Lewes", "st": "11:10", "et": "On time" }"#);
+    assert_eq!(on_time.expected_time(now), Some(at(10, 10)));
+    let cancelled = parse(r#"{ "locationName": "Lewes", "st": "11:10", "et": "Cancelled" }"#);
+    assert_eq!(cancelled.expected_time(now), None);
+    assert_eq!(parse(r#"{ "locationName": "Lewes", "st": "11:10" }"#).expected_time(now), None);
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use chrono::{
+    DateTime,
+    Duration,
+    TimeZone,
+    Utc,
+};
+use rusty_rails::circuit_breaker::{
+    CircuitBreaker,
+    CircuitState,
+};
+
+fn at(minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 3, 10, minute, 0).unwrap()
+}
+
+fn opened_breaker() -> CircuitBreaker {
+    let mut breaker = CircuitBreaker::new(3, Duration::minutes(5));
+    for _ in 0..3 {
+        breaker.record_failure(at(0));
+    }
+    breaker
+}
+
+#[test]
+fn opens_after_consecutive_failures() {
+    let mut breaker = CircuitBreaker::new(3, Duration::minutes(5));
+    breaker.record_failure(at(0));
+    breaker.record_failure(at(0));
+    assert_eq!(breaker.state(at(0)), CircuitState::Closed);
+    breaker.record_failure(at(0));
+    assert_eq!(breaker.state(at(0)), CircuitState::Open);
+    assert!(!breaker.allows_request(at(1)));
+}
+
+#[test]
+fn success_resets_failure_count() {
+    let mut breaker = CircuitBreaker::new(3, Duration::minutes(5));
+    breaker.record_failure(at(0));
+    breaker.record_failure(at(0));
+    breaker.record_success();
+    breaker.record_failure(at(0));
+    assert_eq!(breaker.state(at(0)), CircuitState::Closed);
+}
+
+#[test]
+fn half_open_allows_a_single_probe() {
+    let mut breaker = opened_breaker();
+    assert_eq!(breaker.state(at(5)), CircuitState::HalfOpen);
+    assert!(breaker.allows_request(at(5)));
+    assert!(!breaker.allows_request(at(5)));
+    assert!(!breaker.allows_request(at(6)));
+}
+
+#[test]
+fn successful_probe_closes() {
+    let mut breaker = opened_breaker();
+    assert!(breaker.allows_request(at(5)));
+    breaker.record_success();
+    assert_eq!(breaker.state(at(5)), CircuitState::Closed);
+    assert!(breaker.allows_request(at(5)));
+}
+
+#[test]
+fn failed_probe_reopens_and_restarts_cooldown() {
+    let mut breaker = opened_breaker();
+    assert!(breaker.allows_request(at(5)));
+    breaker.record_failure(at(6));
+    assert_eq!(breaker.state(at(6)), CircuitState::Open);
+    assert_eq!(breaker.state(at(10)), CircuitState::Open);
+    assert_eq!(breaker.state(at(11)), CircuitState::HalfOpen);
+}
\ No newline at end of file
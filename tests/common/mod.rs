@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+use chrono::{
+    DateTime,
+    TimeZone,
+    Utc,
+};
+use rusty_rails::departure::Departure;
+
+pub fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 3, hour, minute, 0).unwrap()
+}
+
+pub fn departure(destination: &str, scheduled_time: DateTime<Utc>, status: &str) -> Departure {
+    Departure::new(destination.to_string(), scheduled_time, None, Vec::new(), Some(1), status.to_string(), None, "Southern".to_string())
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rusty_rails::config::{
+    Config,
+    ConfigError,
+};
+
+static ENVIRONMENT: Mutex<()> = Mutex::new(());
+
+fn config_home(name: &str, contents: &str) -> PathBuf {
+    let home = env::temp_dir().join(format!("rusty_rails_{}_{}", name, process::id()));
+    let directory = home.join("rusty_rails");
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("config.toml"), contents).unwrap();
+    home
+}
+
+fn clear_variables() {
+    for variable in ["RUSTY_RAILS_STATION", "RUSTY_RAILS_REFRESH_INTERVAL", "RUSTY_RAILS_ROWS"] {
+        env::remove_var(variable);
+    }
+}
+
+#[test]
+fn ambiguous_station_name_is_an_error() {
+    let config = Config::default();
+    assert!(matches!(config.resolve_station("london"), Err(ConfigError::AmbiguousStation(_))));
+}
+
+#[test]
+fn file_is_overridden_by_environment_then_command_line() {
+    let _guard = ENVIRONMENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    clear_variables();
+    let home = config_home("precedence", "default_station = \"BTN\"\nrefresh_interval = 60\nrows = 5\n");
+    env::set_var("XDG_CONFIG_HOME", &home);
+    env::set_var("RUSTY_RAILS_REFRESH_INTERVAL", "30");
+    env::set_var("RUSTY_RAILS_ROWS", "8");
+    let config = Config::resolve(Config::new(None, None, Some(12))).unwrap();
+    clear_variables();
+    fs::remove_dir_all(home).unwrap();
+    assert_eq!(config.default_station().as_deref(), Some("BTN"));
+    assert_eq!(config.refresh_interval(), Some(Duration::from_secs(30)));
+    assert_eq!(*config.rows(), Some(12));
+}
+
+#[test]
+fn zero_refresh_interval_is_rejected() {
+    let _guard = ENVIRONMENT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    clear_variables();
+    env::set_var("RUSTY_RAILS_REFRESH_INTERVAL", "0");
+    let from_env = Config::from_env();
+    clear_variables();
+    assert!(matches!(from_env, Err(ConfigError::InvalidRefreshInterval { .. })));
+
+    let home = config_home("zero_interval", "refresh_interval = 0\n");
+    let from_file = Config::load_from(home.join("rusty_rails").join("config.toml"));
+    fs::remove_dir_all(home).unwrap();
+    assert!(matches!(from_file, Err(ConfigError::InvalidRefreshInterval { .. })));
+
+    let from_cli = Config::resolve(Config::new(None, Some(0), None));
+    assert!(matches!(from_cli, Err(ConfigError::InvalidRefreshInterval { .. })));
+}
+
+#[test]
+fn future_config_version_is_rejected() {
+    let home = config_home("version", "version = 2\n");
+    let path = home.join("rusty_rails").join("config.toml");
+    let future = Config::load_from(path.clone());
+    fs::write(&path, "version = 1\nrows = 3\n").unwrap();
+    let current = Config::load_from(path);
+    fs::remove_dir_all(home).unwrap();
+    let future = future.unwrap_err();
+    assert!(matches!(future, ConfigError::UnsupportedVersion { version: 2, .. }));
+    assert!(future.to_string().ends_with("has version 2, but only version 1 is supported; the file was left unchanged"));
+    assert_eq!(*current.unwrap().rows(), Some(3));
+}
+
+#[test]
+fn alias_is_preferred_over_station_name() {
+    let home = config_home("alias", "[aliases]\nbrighton = \"LWS\"\n");
+    let config = Config::load_from(home.join("rusty_rails").join("config.toml"));
+    fs::remove_dir_all(home).unwrap();
+    let config = config.unwrap();
+    assert_eq!(config.resolve_station("Brighton").unwrap().as_str(), "LWS");
+    assert_eq!(config.resolve_station("Lewes").unwrap().as_str(), "LWS");
+    assert_eq!(Config::default().resolve_station("Brighton").unwrap().as_str(), "BTN");
+}
+
+#[test]
+fn aliases_differing_only_by_case_are_rejected() {
+    let home = config_home("duplicate_alias", "[aliases]\nhome = \"BTN\"\nHome = \"LWS\"\nwork = \"VIC\"\n");
+    let config = Config::load_from(home.join("rusty_rails").join("config.toml"));
+    fs::remove_dir_all(home).unwrap();
+    match config {
+        Err(ConfigError::DuplicateAlias { alias, other, .. }) => assert_eq!((alias.as_str(), other.as_str()), ("Home", "home")),
+        other => panic!("expected a duplicate alias error, got {:?}", other),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,298 @@
+mod common;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use chrono::{
+    Duration,
+    NaiveTime,
+};
+use common::{
+    at,
+    departure,
+};
+use rusty_rails::board_event::BoardEvent;
+use rusty_rails::departure::Departure;
+use rusty_rails::departure_board::{
+    DedupeStrategy,
+    DepartureBoard,
+};
+use rusty_rails::nrcc_message::NrccMessage;
+use rusty_rails::ranking::RankingStrategy;
+use rusty_rails::usual_train::UsualTrain;
+
+fn board_with(departures: Vec<Departure>) -> DepartureBoard {
+    let mut board = DepartureBoard::new();
+    for departure in departures {
+        board.add_departure(departure);
+    }
+    board
+}
+
+fn with_service_id(mut departure: Departure, service_id: &str) -> Departure {
+    departure.set_service_id(Some(service_id.to_string()));
+    departure
+}
+
+fn leftover_temporary_files(directory: &Path) -> usize {
+    fs::read_dir(directory).unwrap().filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().ends_with(".tmp")).count()
+}
+
+#[test]
+fn vanished_cancelled_service_is_cancelled() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 30), "Cancelled")]);
+    let events = board.refresh(Vec::new(), at(9, 0));
+    assert!(matches!(events[0], BoardEvent::ServiceCancelled(_)));
+}
+
+#[test]
+fn service_vanishing_near_its_eta_has_departed() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    let events = board.refresh(Vec::new(), at(9, 58));
+    assert!(matches!(events[0], BoardEvent::ServiceDeparted(_)));
+}
+
+#[test]
+fn service_vanishing_long_before_its_eta_is_removed() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    let events = board.refresh(Vec::new(), at(9, 30));
+    assert!(matches!(events[0], BoardEvent::ServiceRemoved(_)));
+}
+
+#[test]
+fn delayed_service_uses_expected_time_for_departure() {
+    let mut delayed = departure("Brighton", at(10, 0), "Delayed");
+    delayed.set_expected_time(Some(at(10, 0) + Duration::minutes(20)));
+    let mut board = board_with(vec![delayed]);
+    let events = board.refresh(Vec::new(), at(10, 5));
+    assert!(matches!(events[0], BoardEvent::ServiceRemoved(_)));
+}
+
+#[test]
+fn refresh_reports_added_updated_and_refreshed() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    let events = board.refresh(vec![departure("Brighton", at(10, 0), "Delayed"), departure("Lewes", at(10, 15), "On time")], at(9, 0));
+    assert!(matches!(events[0], BoardEvent::ServiceUpdated { .. }));
+    assert!(matches!(events[1], BoardEvent::ServiceAdded(_)));
+    assert_eq!(events[2], BoardEvent::BoardRefreshed { services: 2 });
+}
+
+#[test]
+fn merge_dedupe_drops_services_already_on_the_board() {
+    let mut board = board_with(vec![with_service_id(departure("Brighton", at(10, 0), "On time"), "A1")]);
+    let other = board_with(vec![
+        with_service_id(departure("Brighton", at(10, 0), "On time"), "A1"),
+        with_service_id(departure("Lewes", at(10, 15), "On time"), "B2"),
+    ]);
+    board.merge(other, DedupeStrategy::Dedupe);
+    assert_eq!(board.departures().len(), 2);
+}
+
+#[test]
+fn merge_keep_both_keeps_duplicates() {
+    let mut board = board_with(vec![with_service_id(departure("Brighton", at(10, 0), "On time"), "A1")]);
+    let other = board_with(vec![with_service_id(departure("Brighton", at(10, 0), "On time"), "A1")]);
+    board.merge(other, DedupeStrategy::KeepBoth);
+    assert_eq!(board.departures().len(), 2);
+}
+
+#[test]
+fn merge_matches_by_service_id_before_destination_and_time() {
+    let mut board = board_with(vec![with_service_id(departure("Brighton", at(10, 0), "On time"), "A1")]);
+    let other = board_with(vec![
+        with_service_id(departure("Brighton", at(10, 0), "On time"), "C3"),
+        departure("Brighton", at(10, 0), "On time"),
+    ]);
+    board.merge(other, DedupeStrategy::Dedupe);
+    assert_eq!(board.departures().len(), 2);
+}
+
+#[test]
+fn merge_keeps_board_in_eta_order() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 30), "On time")]);
+    board.merge(board_with(vec![departure("Lewes", at(10, 0), "On time")]), DedupeStrategy::Dedupe);
+    assert_eq!(board.departures()[0].destination(), "Lewes");
+}
+
+#[test]
+fn search_matches_destination_operator_and_platform_ignoring_case() {
+    let mut platform_twelve = departure("Lewes", at(10, 15), "On time");
+    platform_twelve.set_platform(Some(12));
+    let board = board_with(vec![departure("Brighton", at(10, 0), "On time"), platform_twelve]);
+    assert_eq!(board.matching("BRIGH").len(), 1);
+    assert_eq!(board.matching("southern").len(), 2);
+    assert_eq!(board.matching("12").len(), 1);
+    assert_eq!(board.matching("2").len(), 1);
+    assert_eq!(board.matching("3").len(), 0);
+}
+
+#[test]
+fn footer_counts_only_rows_matching_the_search() {
+    let mut board = DepartureBoard::with_requested_rows(10);
+    board.add_departure(departure("Brighton", at(10, 0), "On time"));
+    board.add_departure(departure("Lewes", at(10, 15), "On time"));
+    board.set_search(Some("  Brigh ".to_string()));
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert!(rendered.contains("Brighton"));
+    assert!(!rendered.contains("Lewes"));
+    assert!(rendered.ends_with("Showing all 1 service\n"));
+}
+
+#[test]
+fn pager_quitting_early_is_not_an_error() {
+    let mut board = DepartureBoard::new();
+    for index in 0..3000 {
+        board.add_departure(departure(&format!("Destination {}", index), at(10, 0) + Duration::minutes(index), "On time"));
+    }
+    let paged = board.page_with("true");
+    assert!(paged.is_ok(), "{:?}", paged);
+}
+
+#[test]
+fn missing_pager_is_an_error() {
+    let board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    let paged = board.page_with("rusty-rails-missing-pager --quit");
+    assert_eq!(paged.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn buckets_are_not_repeated_under_other_rankings() {
+    let mut delayed = departure("Brighton", at(10, 0), "Delayed");
+    delayed.set_expected_time(Some(at(10, 20)));
+    let mut board = board_with(vec![delayed, departure("Lewes", at(10, 35), "On time"), departure("Seaford", at(10, 10), "On time")]);
+    board.set_timezone(chrono_tz::UTC);
+    board.set_ranking(RankingStrategy::LeastDelayed);
+    board.set_bucket(Some(Duration::minutes(30)));
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert_eq!(rendered.matches("10:00–10:29").count(), 1);
+    assert_eq!(rendered.matches("10:30–10:59").count(), 1);
+    let seaford = rendered.find("Seaford").unwrap();
+    assert!(seaford < rendered.find("Brighton").unwrap());
+    assert!(rendered.find("Brighton").unwrap() < rendered.find("Lewes").unwrap());
+}
+
+#[test]
+fn write_to_file_replaces_target_with_full_render() {
+    let directory = env::temp_dir().join(format!("rusty_rails_write_{}", process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("board.txt");
+    fs::write(&path, "stale").unwrap();
+    let board = board_with(vec![departure("Brighton", at(10, 0), "On time"), departure("Lewes", at(10, 15), "On time")]);
+    board.write_to_file(&path).unwrap();
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    let written = fs::read_to_string(&path).unwrap();
+    let leftovers = leftover_temporary_files(&directory);
+    fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(written, rendered);
+    assert_eq!(leftovers, 0);
+}
+
+#[test]
+fn failed_write_to_file_removes_temporary_file() {
+    let directory = env::temp_dir().join(format!("rusty_rails_write_failure_{}", process::id()));
+    let path = directory.join("board.txt");
+    fs::create_dir_all(&path).unwrap();
+    let board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    let written = board.write_to_file(&path);
+    let leftovers = leftover_temporary_files(&directory);
+    fs::remove_dir_all(&directory).unwrap();
+    assert!(written.is_err());
+    assert_eq!(leftovers, 0);
+}
+
+#[test]
+fn usual_train_is_annotated() {
+    let mut board = board_with(vec![departure("Lewes", at(7, 0), "On time"), departure("Brighton", at(7, 15), "On time")]);
+    board.add_usual_train(UsualTrain::new(NaiveTime::from_hms_opt(8, 15, 0).unwrap(), "Brighton".to_string()));
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert_eq!(rendered.matches("Usual train\n").count(), 1);
+    let annotation = rendered.find("Usual train").unwrap();
+    assert!(rendered.find("Lewes").unwrap() < annotation);
+    assert!(annotation < rendered.find("Brighton").unwrap());
+}
+
+#[test]
+fn bucket_headers_use_board_local_time() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 5), "On time"), departure("Lewes", at(10, 20), "On time"), departure("Seaford", at(10, 40), "On time")]);
+    board.set_bucket(Some(Duration::minutes(30)));
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert!(rendered.starts_with("11:00–11:29\n\n"));
+    assert_eq!(rendered.matches("11:00–11:29").count(), 1);
+    let second_bucket = rendered.find("11:30–11:59\n\n").unwrap();
+    assert!(rendered.find("Lewes").unwrap() < second_bucket);
+    assert!(second_bucket < rendered.find("Seaford").unwrap());
+}
+
+#[test]
+fn zero_bucket_turns_buckets_off() {
+    let mut board = DepartureBoard::new();
+    board.set_bucket(Some(Duration::zero()));
+    assert_eq!(*board.bucket(), None);
+}
+
+#[test]
+fn markdown_cells_cannot_break_the_table() {
+    let mut board = board_with(vec![departure("Brighton | Hove", at(10, 0), "Delayed\nsignal\\failure")]);
+    board.set_timezone(chrono_tz::UTC);
+    let markdown = board.to_markdown();
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "| Scheduled | Destination | Platform | Expected | Status | Operator |");
+    assert_eq!(lines[2], "| 10:00 | Brighton \\| Hove | 1 | - | Delayed signal\\\\failure | Southern |");
+}
+
+#[test]
+fn summaries_only_include_searched_services() {
+    let mut board = board_with(vec![
+        departure("Brighton", at(10, 0), "On time"),
+        departure("Brighton", at(10, 30), "On time"),
+        departure("Lewes", at(10, 15), "On time"),
+        departure("Lewes", at(10, 45), "On time"),
+    ]);
+    board.set_search(Some("brighton".to_string()));
+    let mut summary = String::new();
+    board.render_summary(&mut summary).unwrap();
+    let mut headways = String::new();
+    board.render_headways(&mut headways).unwrap();
+    assert!(summary.contains("Brighton: 2 trains"));
+    assert!(!summary.contains("Lewes"));
+    assert_eq!(headways, "Brighton: about every 30 min\n");
+}
+
+#[test]
+fn search_ignores_case_beyond_ascii() {
+    let mut board = board_with(vec![departure("Ynys Môn", at(10, 0), "On time"), departure("Lewes", at(10, 15), "On time")]);
+    assert_eq!(board.matching("MÔN").len(), 1);
+    board.set_search(Some("YNYS MÔN".to_string()));
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert!(rendered.contains("Ynys Môn"));
+    assert!(!rendered.contains("Lewes"));
+}
+
+#[test]
+fn messages_are_shown_above_the_board() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    board.set_messages(vec![NrccMessage::new("<p>Strike</p>".to_string())]);
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert!(rendered.starts_with("Strike\n\n"));
+}
+
+#[test]
+fn quiet_board_hides_messages() {
+    let mut board = board_with(vec![departure("Brighton", at(10, 0), "On time")]);
+    board.set_messages(vec![NrccMessage::new("<p>Strike</p>".to_string())]);
+    board.set_quiet(true);
+    let mut rendered = String::new();
+    board.render_departures(&mut rendered).unwrap();
+    assert!(!rendered.contains("Strike"));
+    assert!(rendered.contains("Brighton"));
+}
\ No newline at end of file
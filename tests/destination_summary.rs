@@ -0,0 +1,28 @@
+mod common;
+
+use chrono::Duration;
+use common::at;
+use rusty_rails::departure::Departure;
+use rusty_rails::destination_summary::summarise_destinations;
+
+fn departure(destination: &str, minute: i64) -> Departure {
+    common::departure(destination, at(10, 0) + Duration::minutes(minute), "On time")
+}
+
+#[test]
+fn headway_is_median_gap() {
+    let departures = vec![departure("Brighton", 0), departure("Brighton", 12), departure("Brighton", 24), departure("Brighton", 50)];
+    assert_eq!(summarise_destinations(&departures)[0].headway(), Some(Duration::minutes(12)));
+}
+
+#[test]
+fn duplicate_etas_do_not_produce_zero_headway() {
+    let departures = vec![departure("Brighton", 0), departure("Brighton", 0), departure("Brighton", 15), departure("Brighton", 15)];
+    assert_eq!(summarise_destinations(&departures)[0].headway(), Some(Duration::minutes(15)));
+}
+
+#[test]
+fn only_duplicate_etas_have_no_headway() {
+    let departures = vec![departure("Brighton", 0), departure("Brighton", 0)];
+    assert_eq!(summarise_destinations(&departures)[0].headway(), None);
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use chrono::Duration;
+use rusty_rails::interchange::transfer_time;
+use rusty_rails::station_code::StationCode;
+use rusty_rails::trip::Trip;
+
+fn code(code: &str) -> StationCode {
+    StationCode::new(code).unwrap()
+}
+
+#[test]
+fn lookup_is_symmetric() {
+    assert_eq!(transfer_time(&code("EUS"), &code("KGX")), Some(Duration::minutes(15)));
+    assert_eq!(transfer_time(&code("KGX"), &code("EUS")), Some(Duration::minutes(15)));
+    assert_eq!(transfer_time(&code("VIC"), &code("PAD")), Some(Duration::minutes(30)));
+}
+
+#[test]
+fn same_station_needs_no_transfer() {
+    assert_eq!(transfer_time(&code("WAT"), &code("WAT")), Some(Duration::zero()));
+}
+
+#[test]
+fn unknown_pairs_have_no_transfer_time() {
+    assert_eq!(transfer_time(&code("BTN"), &code("LWS")), None);
+    assert_eq!(transfer_time(&code("EUS"), &code("BTN")), None);
+}
+
+#[test]
+fn unknown_pairs_fall_back_to_minimum_interchange() {
+    let trip = Trip::new(Vec::new(), Duration::minutes(7));
+    assert_eq!(trip.required_interchange(&code("BTN"), &code("LWS")), Duration::minutes(7));
+    assert_eq!(trip.required_interchange(&code("BTN"), &code("BTN")), Duration::minutes(7));
+    assert_eq!(trip.required_interchange(&code("PAD"), &code("WAT")), Duration::minutes(30));
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+mod common;
+
+use chrono::Duration;
+use common::at;
+use rusty_rails::board_event::BoardEvent;
+use rusty_rails::departure::Departure;
+use rusty_rails::notifier::{
+    digest_summary,
+    DigestNotifier,
+    Notifier,
+};
+
+type Digests = Rc<RefCell<Vec<(usize, Duration)>>>;
+
+struct RecordingNotifier {
+    digests: Digests,
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify(&mut self, _event: &BoardEvent) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    fn notify_digest(&mut self, events: &[BoardEvent], span: Duration) -> Result<(), Box<dyn Error>> {
+        self.digests.borrow_mut().push((events.len(), span));
+        Ok(())
+    }
+}
+
+fn departure(destination: &str, minute: u32) -> Departure {
+    common::departure(destination, at(10, minute), "On time")
+}
+
+fn updated(departure: Departure) -> BoardEvent {
+    BoardEvent::ServiceUpdated { departure, changes: Vec::new() }
+}
+
+#[test]
+fn summary_counts_distinct_services() {
+    let events = vec![updated(departure("Brighton", 0)), updated(departure("Brighton", 0)), updated(departure("Brighton", 0)), updated(departure("Lewes", 5))];
+    assert_eq!(digest_summary(&events, Duration::minutes(10)), "2 services affected in the last 10 minutes");
+}
+
+#[test]
+fn summary_reports_fetch_failures_separately() {
+    let events = vec![updated(departure("Brighton", 0)), BoardEvent::FetchFailed { reason: "timeout".to_string() }];
+    assert_eq!(
+        digest_summary(&events, Duration::minutes(10)),
+        "1 service affected in the last 10 minutes\nFetching failed 1 time in the last 10 minutes"
+    );
+}
+
+#[test]
+fn digest_reports_real_span_when_flushed_late() {
+    let digests = Digests::default();
+    let mut notifier = DigestNotifier::new(Box::new(RecordingNotifier { digests: digests.clone() }), Duration::minutes(10));
+    notifier.notify_at(&updated(departure("Brighton", 0)), at(10, 0)).unwrap();
+    notifier.notify_at(&updated(departure("Lewes", 0)), at(10, 4)).unwrap();
+    assert!(digests.borrow().is_empty());
+    notifier.notify_at(&updated(departure("Hove", 0)), at(10, 25)).unwrap();
+    assert_eq!(*digests.borrow(), vec![(3, Duration::minutes(25))]);
+    assert!(notifier.pending().is_empty());
+}
+
+#[test]
+fn board_refreshed_is_not_queued() {
+    let digests = Digests::default();
+    let mut notifier = DigestNotifier::new(Box::new(RecordingNotifier { digests: digests.clone() }), Duration::minutes(10));
+    notifier.notify_at(&BoardEvent::BoardRefreshed { services: 3 }, at(10, 0)).unwrap();
+    assert!(notifier.pending().is_empty());
+    notifier.flush_at(at(10, 20)).unwrap();
+    assert!(digests.borrow().is_empty());
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use rusty_rails::nrcc_message::{
+    self,
+    NrccMessage,
+};
+
+#[test]
+fn entities_are_decoded() {
+    assert_eq!(nrcc_message::strip_html("Trains &amp; buses&nbsp;to &quot;Lewes&quot; &lt;via&gt; Brighton&#39;s"), "Trains & buses to \"Lewes\" <via> Brighton's");
+}
+
+#[test]
+fn encoded_entities_are_decoded_once() {
+    assert_eq!(nrcc_message::strip_html("&amp;lt;"), "&lt;");
+}
+
+#[test]
+fn block_tags_separate_words() {
+    assert_eq!(nrcc_message::strip_html("<p>Disruption</p><p>between<br/>Lewes</p><ul><li>and</li><li>Seaford</li></ul>"), "Disruption between Lewes and Seaford");
+}
+
+#[test]
+fn inline_tags_do_not_separate_words() {
+    let message = NrccMessage::new("<p>Use <a href=\"https://example.com\">Nat<b>ional</b> Rail</a> for <!-- note -->updates</p>".to_string());
+    assert_eq!(message.text(), "Use National Rail for updates");
+}
+
+#[test]
+fn literal_less_than_is_kept() {
+    assert_eq!(nrcc_message::strip_html("Expect delays < 10 min today"), "Expect delays < 10 min today");
+    assert_eq!(nrcc_message::strip_html("Delays of <5 minutes"), "Delays of <5 minutes");
+}
+
+#[test]
+fn unclosed_tag_at_end_is_kept_as_text() {
+    assert_eq!(nrcc_message::strip_html("<p>Platform change <b"), "Platform change <b");
+}
+
+#[test]
+fn deserialised_message_is_stripped() {
+    let message: NrccMessage = serde_json::from_str(r#"{ "Value": "<p>Strike &amp; engineering works</p>" }"#).unwrap();
+    assert_eq!(message.value(), "<p>Strike &amp; engineering works</p>");
+    assert_eq!(message.text(), "Strike & engineering works");
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+mod common;
+
+use common::{
+    at,
+    departure,
+};
+use rusty_rails::operator;
+
+#[test]
+fn lookup_by_code_or_name_ignoring_case() {
+    let southern = operator::lookup("sn").unwrap();
+    assert_eq!(southern.name(), "Southern");
+    assert_eq!(operator::lookup(" southern ").unwrap(), southern);
+    assert_eq!(southern.website(), "https://www.southernrailway.com");
+    assert_eq!(southern.social_handle(), "@SouthernRailUK");
+}
+
+#[test]
+fn unknown_operator_has_no_links() {
+    assert_eq!(operator::lookup("Imaginary Railway"), None);
+    assert_eq!(operator::lookup(""), None);
+}
+
+#[test]
+fn operator_codes_are_unique() {
+    for (index, operator) in operator::OPERATORS.iter().enumerate() {
+        assert!(operator::OPERATORS[index + 1..].iter().all(|other| other.code() != operator.code()), "{} appears twice", operator.code());
+    }
+}
+
+#[test]
+fn departure_links_to_its_operator() {
+    let southern = departure("Brighton", at(10, 0), "On time");
+    assert_eq!(southern.operator_info().map(|operator| operator.code()), Some("SN"));
+}
\ No newline at end of file
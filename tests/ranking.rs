@@ -0,0 +1,69 @@
+mod common;
+
+use chrono::Duration;
+use common::{
+    at,
+    departure,
+};
+use rusty_rails::departure::Departure;
+use rusty_rails::departure_board::DepartureBoard;
+use rusty_rails::ranking::{
+    Ranker,
+    RankingStrategy,
+};
+
+fn running_late(destination: &str, hour: u32, minute: u32, delay_minutes: i64) -> Departure {
+    let mut late = departure(destination, at(hour, minute), "Delayed");
+    late.set_expected_time(Some(at(hour, minute) + Duration::minutes(delay_minutes)));
+    late
+}
+
+fn calling_at(destination: &str, hour: u32, minute: u32, calling_points: &[&str]) -> Departure {
+    let scheduled_time = at(hour, minute);
+    Departure::new(destination.to_string(), scheduled_time, None, calling_points.iter().map(|point| point.to_string()).collect(), Some(1), "On time".to_string(), None, "Southern".to_string())
+}
+
+fn destinations(departures: &[Departure]) -> Vec<&str> {
+    departures.iter().map(Departure::destination).collect()
+}
+
+#[test]
+fn least_delayed_puts_cancellations_last_and_punctual_services_first() {
+    let mut departures = vec![
+        departure("Seaford", at(10, 0), "Cancelled"),
+        running_late("Brighton", 10, 5, 15),
+        departure("Lewes", at(10, 20), "On time"),
+        running_late("Hove", 10, 10, 3),
+    ];
+    RankingStrategy::LeastDelayed.sort(&mut departures);
+    assert_eq!(destinations(&departures), vec!["Lewes", "Hove", "Brighton", "Seaford"]);
+}
+
+#[test]
+fn best_for_destination_includes_calling_points() {
+    let mut departures = vec![
+        departure("Seaford", at(10, 0), "On time"),
+        calling_at("Eastbourne", 10, 20, &["Lewes", "Polegate"]),
+        departure("Lewes", at(10, 30), "On time"),
+        calling_at("Hastings", 10, 10, &["lewes"]),
+    ];
+    RankingStrategy::BestForDestination("Lewes".to_string()).sort(&mut departures);
+    assert_eq!(destinations(&departures), vec!["Hastings", "Eastbourne", "Lewes", "Seaford"]);
+}
+
+#[test]
+fn best_for_destination_ranks_cancelled_services_after_running_ones() {
+    let mut departures = vec![departure("Lewes", at(10, 0), "Cancelled"), departure("Lewes", at(10, 30), "On time")];
+    RankingStrategy::BestForDestination("Lewes".to_string()).sort(&mut departures);
+    assert_eq!(departures[0].status(), "On time");
+}
+
+#[test]
+fn custom_ranker_orders_the_board() {
+    let mut board = DepartureBoard::new();
+    board.set_ranking(RankingStrategy::custom(|a: &Departure, b: &Departure| a.destination().cmp(b.destination())));
+    board.add_departure(departure("Seaford", at(10, 0), "On time"));
+    board.add_departure(departure("Brighton", at(10, 30), "On time"));
+    board.add_departure(departure("Lewes", at(10, 15), "On time"));
+    assert_eq!(destinations(board.departures()), vec!["Brighton", "Lewes", "Seaford"]);
+}
\ No newline at end of file
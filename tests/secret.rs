@@ -0,0 +1,43 @@
+use rusty_rails::secret::Secret;
+
+const KEY: &str = "0123456789abcdef";
+
+#[derive(Debug)]
+struct Credentials {
+    #[allow(dead_code)]
+    departures_key: Secret<String>,
+}
+
+#[test]
+fn debug_is_redacted() {
+    let secret = Secret::new(KEY.to_string());
+    assert_eq!(format!("{:?}", secret), "[REDACTED]");
+    assert!(!format!("{:#?}", secret).contains(KEY));
+}
+
+#[test]
+fn display_is_redacted() {
+    let secret = Secret::new(KEY.to_string());
+    assert_eq!(secret.to_string(), "[REDACTED]");
+}
+
+#[test]
+fn containing_struct_debug_is_redacted() {
+    let credentials = Credentials { departures_key: KEY.to_string().into() };
+    let debug = format!("{:?}", credentials);
+    assert!(!debug.contains(KEY));
+    assert!(debug.contains("[REDACTED]"));
+}
+
+#[test]
+fn error_message_is_redacted() {
+    let secret = Secret::new(KEY.to_string());
+    let error = std::io::Error::other(format!("request rejected for key {}", secret));
+    assert!(!error.to_string().contains(KEY));
+}
+
+#[test]
+fn expose_returns_value() {
+    let secret = Secret::new(KEY.to_string());
+    assert_eq!(secret.expose(), KEY);
+}
\ No newline at end of file
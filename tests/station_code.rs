@@ -0,0 +1,34 @@
+use rusty_rails::station_code::{
+    StationCode,
+    StationCodeError,
+};
+
+#[test]
+fn codes_are_trimmed_and_uppercased() {
+    assert_eq!(StationCode::new("  kgx \n").unwrap().as_str(), "KGX");
+    assert_eq!("Vic".parse::<StationCode>().unwrap().to_string(), "VIC");
+}
+
+#[test]
+fn empty_code_is_rejected() {
+    let error = StationCode::new("   ").unwrap_err();
+    assert_eq!(error, StationCodeError::Empty);
+    assert_eq!(error.to_string(), "station code is empty; expected 3 letters such as KGX");
+}
+
+#[test]
+fn wrong_length_is_rejected() {
+    let error = StationCode::new("KGXX").unwrap_err();
+    assert_eq!(error, StationCodeError::WrongLength { code: "KGXX".to_string(), length: 4 });
+    assert_eq!(error.to_string(), "station code 'KGXX' has 4 letters; expected exactly 3");
+    assert_eq!(StationCode::new("kg").unwrap_err().to_string(), "station code 'kg' has 2 letters; expected exactly 3");
+}
+
+#[test]
+fn invalid_characters_are_rejected() {
+    let error = StationCode::new("K1X").unwrap_err();
+    assert_eq!(error, StationCodeError::InvalidCharacter { code: "K1X".to_string(), character: '1' });
+    assert_eq!(error.to_string(), "station code 'K1X' contains '1'; only the letters A-Z are allowed");
+    assert_eq!(StationCode::new("K X").unwrap_err().to_string(), "station code 'K X' contains ' '; only the letters A-Z are allowed");
+    assert_eq!(StationCode::new("kgé").unwrap_err().to_string(), "station code 'kgé' contains 'é'; only the letters A-Z are allowed");
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use rusty_rails::stations::{
+    self,
+    ResolveError,
+};
+
+#[test]
+fn shared_top_score_is_ambiguous() {
+    match stations::resolve("london") {
+        Err(ResolveError::Ambiguous { query, candidates }) => {
+            assert_eq!(query, "london");
+            assert!(candidates.iter().any(|station| station.crs().as_str() == "EUS"));
+            assert!(candidates.iter().any(|station| station.crs().as_str() == "VIC"));
+        }
+        other => panic!("expected an ambiguous match, got {:?}", other),
+    }
+}
+
+#[test]
+fn ambiguity_lists_candidates() {
+    let error = stations::resolve("london").unwrap_err();
+    assert!(error.to_string().contains("London Bridge (LBG)"));
+}
+
+#[test]
+fn unique_best_match_resolves() {
+    assert_eq!(stations::resolve("brighton").unwrap().as_str(), "BTN");
+    assert_eq!(stations::resolve("London Waterloo East").unwrap().as_str(), "WAE");
+}
+
+#[test]
+fn known_codes_resolve_directly() {
+    assert_eq!(stations::resolve(" lws ").unwrap().as_str(), "LWS");
+}
+
+#[test]
+fn unknown_well_formed_codes_pass_through() {
+    assert_eq!(stations::resolve("xyz").unwrap().as_str(), "XYZ");
+}
+
+#[test]
+fn unmatched_names_are_unknown() {
+    assert_eq!(stations::resolve("nowhere at all"), Err(ResolveError::Unknown("nowhere at all".to_string())));
+    assert_eq!(stations::resolve("  "), Err(ResolveError::Unknown(String::new())));
+}
\ No newline at end of file
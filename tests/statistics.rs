@@ -0,0 +1,62 @@
+mod common;
+
+use chrono::Duration;
+use common::{
+    at,
+    departure,
+};
+use rusty_rails::board_event::BoardEvent;
+use rusty_rails::departure::Departure;
+use rusty_rails::statistics::Statistics;
+
+fn running(destination: &str, delay_minutes: i64) -> Departure {
+    let mut running = departure(destination, at(10, 0), if delay_minutes > 0 { "Delayed" } else { "On time" });
+    running.set_expected_time(Some(at(10, 0) + Duration::minutes(delay_minutes)));
+    running
+}
+
+#[test]
+fn observations_outside_the_window_are_not_counted() {
+    let mut statistics = Statistics::with_window(Duration::hours(1));
+    statistics.record(&running("Brighton", 5), at(10, 0));
+    assert_eq!(statistics.by_operator(at(10, 30))["Southern"].services(), 1);
+    assert!(statistics.by_operator(at(11, 0)).is_empty());
+    assert!(statistics.by_destination(at(12, 0)).is_empty());
+}
+
+#[test]
+fn recording_prunes_expired_observations() {
+    let mut statistics = Statistics::with_window(Duration::hours(1));
+    statistics.record(&running("Brighton", 5), at(10, 0));
+    statistics.record(&running("Lewes", 0), at(11, 30));
+    statistics.prune(at(11, 30));
+    let by_destination = statistics.by_destination(at(9, 0));
+    assert!(!by_destination.contains_key("Brighton"));
+}
+
+#[test]
+fn operator_average_includes_early_running_and_skips_cancellations() {
+    let mut statistics = Statistics::new();
+    statistics.record(&running("Brighton", 10), at(10, 0));
+    statistics.record(&running("Lewes", -2), at(10, 5));
+    statistics.record(&departure("Seaford", at(10, 10), "Cancelled"), at(10, 10));
+    let southern = &statistics.by_operator(at(10, 15))["Southern"];
+    assert_eq!(southern.services(), 3);
+    assert_eq!(southern.cancellations(), 1);
+    assert_eq!(southern.average_delay(), Duration::minutes(4));
+}
+
+#[test]
+fn only_departures_and_cancellations_are_recorded() {
+    let mut statistics = Statistics::new();
+    let service = running("Brighton", 3);
+    statistics.record_event(&BoardEvent::ServiceAdded(service.clone()), at(10, 0));
+    statistics.record_event(&BoardEvent::ServiceRemoved(service.clone()), at(10, 0));
+    statistics.record_event(&BoardEvent::BoardRefreshed { services: 1 }, at(10, 0));
+    assert!(statistics.by_operator(at(10, 0)).is_empty());
+    statistics.record_event(&BoardEvent::ServiceDeparted(service), at(10, 0));
+    statistics.record_event(&BoardEvent::ServiceCancelled(departure("Lewes", at(10, 5), "Cancelled")), at(10, 5));
+    let southern = &statistics.by_operator(at(10, 5))["Southern"];
+    assert_eq!(southern.services(), 2);
+    assert_eq!(southern.cancellations(), 1);
+}
\ No newline at end of file
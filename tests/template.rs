@@ -0,0 +1,52 @@
+mod common;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use common::at;
+use chrono_tz::Tz;
+use rusty_rails::departure::Departure;
+use rusty_rails::departure_board::DepartureBoard;
+use rusty_rails::template::{
+    self,
+    TemplateError,
+};
+
+fn departure(expected_time: Option<DateTime<Utc>>, status: &str) -> Departure {
+    let mut departure = common::departure("Brighton", at(10, 0), status);
+    departure.set_expected_time(expected_time);
+    departure
+}
+
+#[test]
+fn etd_shows_cancelled_for_cancelled_services() {
+    let rendered = template::render("{etd}", &departure(None, "Cancelled"), &Tz::UTC);
+    assert_eq!(rendered, Ok("Cancelled".to_string()));
+}
+
+#[test]
+fn etd_shows_expected_time_for_delayed_services() {
+    let rendered = template::render("{std} {etd}", &departure(Some(at(10, 7)), "Delayed"), &Tz::UTC);
+    assert_eq!(rendered, Ok("10:00 10:07".to_string()));
+}
+
+#[test]
+fn etd_shows_on_time_without_expected_time() {
+    let rendered = template::render("{etd}", &departure(None, "On time"), &Tz::UTC);
+    assert_eq!(rendered, Ok("On time".to_string()));
+}
+
+#[test]
+fn etd_shows_status_for_delayed_services_without_expected_time() {
+    let rendered = template::render("{etd}", &departure(None, "Delayed"), &Tz::UTC);
+    assert_eq!(rendered, Ok("Delayed".to_string()));
+}
+
+#[test]
+fn malformed_template_is_reported_on_an_empty_board() {
+    let board = DepartureBoard::new();
+    assert_eq!(board.format_first("{platfrom}"), Err(TemplateError::UnknownField("platfrom".to_string())));
+    assert_eq!(board.format_first("{destination"), Err(TemplateError::UnclosedBrace));
+    assert_eq!(board.format_first("{destination}"), Ok(None));
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use chrono::{
+    TimeZone,
+    Utc,
+};
+use rusty_rails::times::{
+    resolve_time,
+    BOARD_TIMEZONE,
+};
+
+#[test]
+fn just_after_midnight_resolves_to_tomorrow() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 1, 1, 23, 58, 0).unwrap();
+    assert_eq!(resolve_time("00:05", &now), Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 5, 0).unwrap()));
+}
+
+#[test]
+fn just_before_midnight_resolves_to_yesterday() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 1, 2, 0, 3, 0).unwrap();
+    assert_eq!(resolve_time("23:58", &now), Some(Utc.with_ymd_and_hms(2024, 1, 1, 23, 58, 0).unwrap()));
+}
+
+#[test]
+fn same_day_time_resolves_to_today() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    assert_eq!(resolve_time(" 12:30 ", &now), Some(Utc.with_ymd_and_hms(2024, 1, 1, 12, 30, 0).unwrap()));
+}
+
+#[test]
+fn clocks_going_forward_uses_summer_time_offset() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 3, 31, 8, 0, 0).unwrap();
+    assert_eq!(resolve_time("08:30", &now), Some(Utc.with_ymd_and_hms(2024, 3, 31, 7, 30, 0).unwrap()));
+}
+
+#[test]
+fn time_in_spring_forward_gap_moves_forward_an_hour() {
+    let now = Utc.with_ymd_and_hms(2024, 3, 31, 0, 50, 0).unwrap().with_timezone(&BOARD_TIMEZONE);
+    assert_eq!(resolve_time("01:30", &now), Some(Utc.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap()));
+}
+
+#[test]
+fn clocks_going_back_uses_earliest_of_repeated_hour() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 10, 27, 0, 50, 0).unwrap();
+    assert_eq!(resolve_time("01:30", &now), Some(Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap()));
+}
+
+#[test]
+fn clocks_going_back_uses_winter_offset_after_change() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 10, 27, 9, 55, 0).unwrap();
+    assert_eq!(resolve_time("10:00", &now), Some(Utc.with_ymd_and_hms(2024, 10, 27, 10, 0, 0).unwrap()));
+}
+
+#[test]
+fn non_time_values_do_not_resolve() {
+    let now = BOARD_TIMEZONE.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    assert_eq!(resolve_time("On time", &now), None);
+    assert_eq!(resolve_time("25:00", &now), None);
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+mod common;
+
+use chrono::Duration;
+use common::at;
+use rusty_rails::station_code::StationCode;
+use rusty_rails::trip::{
+    Leg,
+    Trip,
+};
+
+fn code(code: &str) -> StationCode {
+    StationCode::new(code).unwrap()
+}
+
+fn leg(from: &str, to: &str, departure: (u32, u32), arrival: (u32, u32)) -> Leg {
+    Leg::new(code(from), code(to), at(departure.0, departure.1), at(arrival.0, arrival.1))
+}
+
+fn brighton_to_cambridge() -> Trip {
+    Trip::new(vec![leg("BTN", "STP", (9, 0), (10, 0)), leg("KGX", "CBG", (10, 20), (11, 10))], Duration::minutes(5))
+}
+
+#[test]
+fn interchange_time_uses_expected_times() {
+    let mut trip = brighton_to_cambridge();
+    assert_eq!(*trip.interchanges()[0].available(), Duration::minutes(20));
+    trip.legs_mut()[0].set_expected_arrival(Some(at(10, 4)));
+    trip.legs_mut()[1].set_expected_departure(Some(at(10, 22)));
+    assert_eq!(*trip.interchanges()[0].available(), Duration::minutes(18));
+    assert!(trip.is_achievable());
+}
+
+#[test]
+fn delay_below_required_interchange_breaks_connection() {
+    let mut trip = brighton_to_cambridge();
+    trip.legs_mut()[0].set_expected_arrival(Some(at(10, 12)));
+    let broken = trip.broken_interchanges();
+    assert_eq!(broken.len(), 1);
+    assert_eq!(*broken[0].arriving_at(), code("STP"));
+    assert_eq!(*broken[0].departing_from(), code("KGX"));
+    assert!(!trip.is_achievable());
+}
+
+#[test]
+fn cancelled_connecting_leg_is_not_achievable() {
+    let mut trip = brighton_to_cambridge();
+    trip.legs_mut()[1].set_cancelled(true);
+    assert!(trip.interchanges()[0].is_cancelled());
+    assert_eq!(trip.broken_interchanges().len(), 1);
+    assert!(!trip.is_achievable());
+}
+
+#[test]
+fn cancelled_single_leg_trip_is_not_achievable() {
+    let mut trip = Trip::new(vec![leg("BTN", "LWS", (9, 0), (9, 20))], Duration::minutes(5));
+    assert!(trip.is_achievable());
+    trip.legs_mut()[0].set_cancelled(true);
+    assert!(!trip.is_achievable());
+}
+
+#[test]
+fn required_interchange_is_the_larger_of_minimum_and_transfer_time() {
+    let trip = brighton_to_cambridge();
+    assert_eq!(trip.required_interchange(&code("STP"), &code("KGX")), Duration::minutes(10));
+    let cautious = Trip::new(Vec::new(), Duration::minutes(12));
+    assert_eq!(cautious.required_interchange(&code("STP"), &code("KGX")), Duration::minutes(12));
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+mod common;
+
+use chrono::{
+    NaiveTime,
+    TimeZone,
+    Utc,
+};
+use common::departure;
+use rusty_rails::usual_train::UsualTrain;
+
+fn eight_fifteen_to_brighton() -> UsualTrain {
+    UsualTrain::new(NaiveTime::from_hms_opt(8, 15, 0).unwrap(), "Brighton".to_string())
+}
+
+#[test]
+fn matches_local_time_in_winter() {
+    let winter = Utc.with_ymd_and_hms(2024, 1, 15, 8, 15, 0).unwrap();
+    assert!(eight_fifteen_to_brighton().matches(&departure("brighton", winter, "On time")));
+}
+
+#[test]
+fn matches_local_time_in_summer() {
+    let summer = Utc.with_ymd_and_hms(2024, 6, 3, 7, 15, 0).unwrap();
+    assert!(eight_fifteen_to_brighton().matches(&departure("Brighton", summer, "On time")));
+    let an_hour_late = Utc.with_ymd_and_hms(2024, 6, 3, 8, 15, 0).unwrap();
+    assert!(!eight_fifteen_to_brighton().matches(&departure("Brighton", an_hour_late, "On time")));
+}
+
+#[test]
+fn other_destinations_do_not_match() {
+    let winter = Utc.with_ymd_and_hms(2024, 1, 15, 8, 15, 0).unwrap();
+    assert!(!eight_fifteen_to_brighton().matches(&departure("Lewes", winter, "On time")));
+}
\ No newline at end of file